@@ -0,0 +1,46 @@
+//!Compares the plain linear scan of `VecMap` against `FingerprintVecMap` for string keys which share a long prefix,
+//!so that every full key comparison has to walk most of the string.
+//!
+//!Run with `cargo bench --bench fingerprint`. Requires a nightly toolchain for `#![feature(test)]`.
+#![feature(test)]
+extern crate test;
+
+use linear_collections::{FingerprintVecMap, LinearMapView, TryFromIterator, VecMap};
+use test::{black_box, Bencher};
+
+const LEN: usize = 64;
+
+fn key(i: usize) -> String {
+    format!("/usr/share/linear_collections/resources/{i:04}")
+}
+
+///Every key in the map, followed by as many keys which are not.
+fn lookups() -> std::vec::Vec<String> {
+    (0..2 * LEN).map(key).collect()
+}
+
+fn pairs() -> impl Iterator<Item = (String, usize)> {
+    (0..LEN).map(|i| (key(i), i))
+}
+
+#[bench]
+fn vec_map_scan(b: &mut Bencher) {
+    let map = VecMap::try_from_iter(pairs()).unwrap();
+    let keys = lookups();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get(black_box(k.as_str())))
+            .sum::<usize>()
+    });
+}
+
+#[bench]
+fn fingerprint_vec_map(b: &mut Bencher) {
+    let map = FingerprintVecMap::<_, _>::try_from_iter(pairs()).unwrap();
+    let keys = lookups();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get(black_box(k.as_str())))
+            .sum::<usize>()
+    });
+}
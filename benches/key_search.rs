@@ -0,0 +1,94 @@
+//!Compares the SIMD key searches used for primitive keys against the plain linear scan used for every other key
+//!type. `Opaque` wraps the same integers, but isn't a primitive, so maps keyed by it take the linear scan.
+//!`ArrayMap` compares every chunk of keys without branching, as the length of the array is known up front.
+//!`array_map_position` copies the same pairs into a `VecMap`, which searches them as a slice, stopping at the first
+//!chunk holding the key.
+//!
+//!Run with `cargo bench --bench key_search`. Requires a nightly toolchain for `#![feature(test)]`.
+#![feature(test)]
+extern crate test;
+
+use linear_collections::{
+    array::map::ArrayMap, FallibleLinearMap, LinearMapView, StackMap, TryFromIterator, VecMap,
+};
+use test::{black_box, Bencher};
+
+const LEN: usize = 32;
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+struct Opaque(u32);
+
+///Every key in the map, followed by as many keys which are not.
+fn lookups() -> std::vec::Vec<u32> {
+    (0..2 * LEN as u32).map(|k| k * 7).collect()
+}
+
+fn array_map<K: Eq>(key: impl Fn(u32) -> K) -> ArrayMap<K, u32, LEN> {
+    //SAFETY: the keys are distinct.
+    unsafe {
+        ArrayMap::from_array_unchecked(std::array::from_fn(|i| (key(i as u32 * 14), i as u32)))
+    }
+}
+
+fn stack_map<K: Eq>(key: impl Fn(u32) -> K) -> StackMap<K, u32, LEN> {
+    let mut map = StackMap::new();
+    for i in 0..LEN as u32 {
+        map.insert(key(i * 14), i).unwrap();
+    }
+    map
+}
+
+#[bench]
+fn array_map_scan(b: &mut Bencher) {
+    let map = array_map(Opaque);
+    let keys = lookups();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get(black_box(&Opaque(*k))))
+            .sum::<u32>()
+    });
+}
+
+#[bench]
+fn array_map_unrolled(b: &mut Bencher) {
+    let map = array_map(|k| k);
+    let keys = lookups();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get(black_box(k)))
+            .sum::<u32>()
+    });
+}
+
+#[bench]
+fn array_map_position(b: &mut Bencher) {
+    let map = VecMap::try_from_iter(array_map(|k| k).into_inner()).unwrap();
+    let keys = lookups();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get(black_box(k)))
+            .sum::<u32>()
+    });
+}
+
+#[bench]
+fn stack_map_scan(b: &mut Bencher) {
+    let map = stack_map(Opaque);
+    let keys = lookups();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get(black_box(&Opaque(*k))))
+            .sum::<u32>()
+    });
+}
+
+#[bench]
+fn stack_map_simd(b: &mut Bencher) {
+    let map = stack_map(|k| k);
+    let keys = lookups();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get(black_box(k)))
+            .sum::<u32>()
+    });
+}
@@ -0,0 +1,103 @@
+//!Compares the plain linear scan of `VecMap` and `DequeMap` against their self-organizing counterparts
+//!under a skewed workload, where most lookups hit a few hot keys which were inserted last.
+//!
+//!Run with `cargo bench --bench self_organizing`. Requires a nightly toolchain for `#![feature(test)]`.
+#![feature(test)]
+extern crate test;
+
+use linear_collections::{
+    DequeMap, LinearMapView, MoveToFront, SelfOrganizingDequeMap, SelfOrganizingVecMap, Transpose,
+    TryFromIterator, VecMap,
+};
+use test::{black_box, Bencher};
+
+const LEN: u64 = 64;
+const HOT: u64 = 4;
+const LOOKUPS: usize = 1024;
+
+///Nine in ten lookups hit one of the `HOT` keys at the back of the map, the rest are spread over every key.
+fn skewed_keys() -> std::vec::Vec<u64> {
+    //a small xorshift so the workload is the same on every run.
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..LOOKUPS)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            match state % 10 {
+                0 => state % LEN,
+                _ => LEN - 1 - state % HOT,
+            }
+        })
+        .collect()
+}
+
+fn pairs() -> impl Iterator<Item = (u64, u64)> {
+    (0..LEN).map(|k| (k, k))
+}
+
+#[bench]
+fn vec_map_scan(b: &mut Bencher) {
+    let map = VecMap::try_from_iter(pairs()).unwrap();
+    let keys = skewed_keys();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get(black_box(k)))
+            .sum::<u64>()
+    });
+}
+
+#[bench]
+fn vec_map_move_to_front(b: &mut Bencher) {
+    let mut map = SelfOrganizingVecMap::<_, _, MoveToFront>::try_from_iter(pairs()).unwrap();
+    let keys = skewed_keys();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get_and_promote(black_box(k)).copied())
+            .sum::<u64>()
+    });
+}
+
+#[bench]
+fn vec_map_transpose(b: &mut Bencher) {
+    let mut map = SelfOrganizingVecMap::<_, _, Transpose>::try_from_iter(pairs()).unwrap();
+    let keys = skewed_keys();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get_and_promote(black_box(k)).copied())
+            .sum::<u64>()
+    });
+}
+
+#[bench]
+fn deque_map_scan(b: &mut Bencher) {
+    let map = DequeMap::try_from_iter(pairs()).unwrap();
+    let keys = skewed_keys();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get(black_box(k)))
+            .sum::<u64>()
+    });
+}
+
+#[bench]
+fn deque_map_move_to_front(b: &mut Bencher) {
+    let mut map = SelfOrganizingDequeMap::<_, _, MoveToFront>::try_from_iter(pairs()).unwrap();
+    let keys = skewed_keys();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get_and_promote(black_box(k)).copied())
+            .sum::<u64>()
+    });
+}
+
+#[bench]
+fn deque_map_transpose(b: &mut Bencher) {
+    let mut map = SelfOrganizingDequeMap::<_, _, Transpose>::try_from_iter(pairs()).unwrap();
+    let keys = skewed_keys();
+    b.iter(|| {
+        keys.iter()
+            .filter_map(|k| map.get_and_promote(black_box(k)).copied())
+            .sum::<u64>()
+    });
+}
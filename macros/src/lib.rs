@@ -63,6 +63,32 @@ fn validate_map_literal(input: &MapLiteral) {
         panic!("Duplicate keys found: {duplicates:?}")
     }
 }
+
+///The code for checking the input elements is identical between all sets
+///and factored into this function.
+fn validate_set_literal(input: &SetLiteral) {
+    let elements = &input.inner;
+
+    if elements.is_empty() {
+        panic!("Input may not be empty")
+    };
+
+    let mut duplicates: Vec<String> = Vec::new();
+    let mut values: BTreeSet<String> = BTreeSet::new();
+
+    for e in elements.iter() {
+        let value = e.into_token_stream().to_string();
+
+        if !values.insert(value.clone()) {
+            duplicates.push(value)
+        }
+    }
+
+    if !duplicates.is_empty() {
+        panic!("Duplicate values found: {duplicates:?}")
+    }
+}
+
 #[allow(unused_imports)]
 #[proc_macro]
 ///NOTE: This macro does not currently consider prefixed or suffixed items (r"t" and "t" or 1 and 1usize) to be
@@ -85,7 +111,26 @@ pub fn array_map(tokens: TokenStream) -> TokenStream {
     .into()
 }
 
+#[proc_macro]
+///NOTE: This macro does not currently consider prefixed or suffixed items (r"t" and "t" or 1 and 1usize) to be
+///distinct. This is highly likely to change in the future and this fix may not be considered a breaking change.
+///
+///Creates an ArraySet, checking at compile time that there are no duplicate values.
+///Example:
+///`let set: ArraySet<char, 3> = array_set!['A', 'B', 'C'];`
+pub fn array_set(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as SetLiteral);
+
+    validate_set_literal(&input);
 
+    let iter = input.inner.iter();
+    quote! {
+        unsafe{
+            linear_collections::array::set::ArraySet::from_array_unchecked([#(#iter),*])
+        }
+    }
+    .into()
+}
 
 #[proc_macro]
 ///NOTE: This macro does not currently consider prefixed or suffixed items (r"t" and "t" or 1 and 1usize) to be
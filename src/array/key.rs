@@ -0,0 +1,45 @@
+///Keys which can be compared for equality in `const` context, letting an `ArrayMap` be queried in `const` items and const generics.
+///
+///`PartialEq` can't be called in `const fn`s, so this is implemented for integers, `char`, `bool`, `&str` and `&[u8]`,
+///whose equality can be written out by hand.
+pub const trait ConstKey {
+    ///Returns true if `self` and `other` are equal, exactly as `PartialEq::eq` would.
+    fn const_eq(&self, other: &Self) -> bool;
+}
+
+macro_rules! const_key {
+    ($($key:ty),*) => {$(
+        impl const ConstKey for $key {
+            fn const_eq(&self, other: &Self) -> bool {
+                *self == *other
+            }
+        }
+    )*};
+}
+
+const_key!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, bool);
+
+impl const ConstKey for &[u8] {
+    fn const_eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        //iterators can't be used in `const fn`s.
+        let mut index = 0;
+        while index < self.len() {
+            if self[index] != other[index] {
+                return false;
+            }
+            index += 1;
+        }
+
+        true
+    }
+}
+
+impl const ConstKey for &str {
+    fn const_eq(&self, other: &Self) -> bool {
+        self.as_bytes().const_eq(&other.as_bytes())
+    }
+}
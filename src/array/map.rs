@@ -1,8 +1,21 @@
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+use std::{borrow::Borrow, collections::TryReserveError};
+
+use crate::{
+    array::ConstKey, search::KeySearch, LinearMapValuesMut, LinearMapView, MapIndex, MapIterMut,
+    TryClone,
+};
+
+#[derive(Clone)]
 ///A map type backed by an Array, stack allocated and fixed in size.
 ///
-///ArrayMap is the only map type in linear_collections which does *not* implement either LinearMap nor InfallibleLinearMap, which relies on
-///dynamic memory allocation to function.
+///ArrayMap is the only map type in linear_collections which does *not* implement either FallibleLinearMap nor InfallibleLinearMap, as its
+///length can never change. It does implement `LinearMapView` and `LinearMapValuesMut`, so it can be passed to generic code which
+///only reads a map or mutates its values.
+///
+///Lookups in `const` context go through `const_get`, `const_index_of` and `const_contains_key` rather than `get` and
+///`LinearMapView::contains_key`. Those accept any borrowed form of the key and search primitive keys with SIMD,
+///neither of which a `const fn` can do, as `Borrow` and `PartialEq` are not const traits. Bounding them on `ConstKey`
+///instead would bar every other key type, such as `String`, from `ArrayMap`'s lookups, so the const versions are kept apart.
 pub struct ArrayMap<K: Eq, V, const LENGTH: usize> {
     array: [(K, V); LENGTH],
 }
@@ -34,15 +47,65 @@ impl<K: Eq, V, const LENGTH: usize> ArrayMap<K, V, LENGTH> {
         LENGTH == 0
     }
 
-    pub fn get<'a>(&'a self, key: &K) -> Option<&'a V> {
-        self.array.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    ///Gets a reference to the value associated with the key. Will return None if that key is not in the map.
+    ///The key may be any borrowed form of the map's key type.
+    pub fn get<'a, Q: ?Sized + Eq>(&'a self, key: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = K::position_in_array(&self.array, key)?;
+        //SAFETY: `position_in_array` only returns indices within the array.
+        Some(&unsafe { self.array.get_unchecked(index) }.1)
     }
 
-    pub fn get_mut<'a>(&'a mut self, key: &K) -> Option<&'a mut V> {
-        self.array
-            .iter_mut()
-            .find(|(k, _)| k == key)
-            .map(|(_, v)| v)
+    ///Gets an exclusive reference to the value associated with the key. Will return None if that key is not in the map.
+    ///The key may be any borrowed form of the map's key type.
+    pub fn get_mut<'a, Q: ?Sized + Eq>(&'a mut self, key: &Q) -> Option<&'a mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = K::position_in_array(&self.array, key)?;
+        //SAFETY: `position_in_array` only returns indices within the array.
+        Some(&mut unsafe { self.array.get_unchecked_mut(index) }.1)
+    }
+
+    ///Returns the index of the pair whose key is equal to `key`, or None if it is not in the map.
+    ///Unlike the other lookups, this may be called in `const` context, though `key` must be the key type itself.
+    pub const fn const_index_of(&self, key: &K) -> Option<usize>
+    where
+        K: [const] ConstKey,
+    {
+        //iterators can't be used in `const fn`s.
+        let mut index = 0;
+        while index < LENGTH {
+            if self.array[index].0.const_eq(key) {
+                return Some(index);
+            }
+            index += 1;
+        }
+
+        None
+    }
+
+    ///Gets a reference to the value associated with the key. Will return None if that key is not in the map.
+    ///Unlike `get`, this may be called in `const` context, though `key` must be the key type itself.
+    pub const fn const_get(&self, key: &K) -> Option<&V>
+    where
+        K: [const] ConstKey,
+    {
+        match self.const_index_of(key) {
+            Some(index) => Some(&self.array[index].1),
+            None => None,
+        }
+    }
+
+    ///Returns true if this map contains the given key. False otherwise.
+    ///This may be called in `const` context, though `key` must be the key type itself.
+    pub const fn const_contains_key(&self, key: &K) -> bool
+    where
+        K: [const] ConstKey,
+    {
+        self.const_index_of(key).is_some()
     }
 
     pub fn into_inner(self) -> [(K, V); LENGTH] {
@@ -69,3 +132,70 @@ impl<K: Eq, V, const LENGTH: usize> ArrayMap<K, V, LENGTH> {
         self.array.iter_mut().map(|(_, v)| v)
     }
 }
+
+impl<K: Eq, V, const LENGTH: usize> LinearMapView<K, V> for ArrayMap<K, V, LENGTH> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.array.iter().map(|(k, v)| (k, v))
+    }
+
+    fn len(&self) -> usize {
+        LENGTH
+    }
+}
+
+impl<K: Eq, V, const LENGTH: usize> LinearMapValuesMut<K, V> for ArrayMap<K, V, LENGTH> {}
+
+impl<K: Eq, V, const LENGTH: usize> MapIterMut<K, V> for ArrayMap<K, V, LENGTH> {
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.array.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Eq, V, const LENGTH: usize> MapIndex<K, V> for ArrayMap<K, V, LENGTH> {
+    fn index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        K::position_in_array(&self.array, key)
+    }
+
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.array.get_unchecked(index) };
+        (k, v)
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.array.get_unchecked_mut(index) };
+        (k, v)
+    }
+}
+
+impl<K: Eq + Clone, V: Clone, const LENGTH: usize> TryClone for ArrayMap<K, V, LENGTH> {
+    ///Never fails, as `ArrayMap` does not allocate.
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(self.clone())
+    }
+}
+
+impl<K: Eq, V, const LENGTH: usize> IntoIterator for ArrayMap<K, V, LENGTH> {
+    type Item = (K, V);
+
+    type IntoIter = std::array::IntoIter<(K, V), LENGTH>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.array.into_iter()
+    }
+}
+
+crate::std_traits::map_traits!([K: Eq, V, const LENGTH: usize] ArrayMap<K, V, LENGTH>);
+crate::std_traits::map_index!([K: Eq, V, const LENGTH: usize] ArrayMap<K, V, LENGTH>);
@@ -1,7 +1,11 @@
+mod key;
 pub mod map;
+pub mod set;
+
+pub use key::ConstKey;
 
 #[cfg(feature = "macros")]
-pub use linear_collections_macros::array_map;
+pub use linear_collections_macros::{array_map, array_set};
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,136 @@
+use std::{
+    borrow::Borrow,
+    collections::TryReserveError,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::TryClone;
+
+#[derive(Clone)]
+///A set type backed by an Array, stack allocated and fixed in size.
+///
+///Like `ArrayMap`, ArraySet does not implement `FallibleLinearSet`, as its length can never change.
+pub struct ArraySet<T: Eq, const LENGTH: usize> {
+    array: [T; LENGTH],
+}
+
+impl<T: Eq, const LENGTH: usize> ArraySet<T, LENGTH> {
+    ///**Please only use this method to create set literals if the "macros" feature is unavailable to you**
+    ///"macros" provides safe, checked alternatives to initialize linear sets with compile time checking
+    ///of the invariants of each type.
+    ///
+    ///Creates a new ArraySet from the supplied array.
+    ///
+    ///# Safety
+    ///Improper use of this method - initializing with duplicate values - will NOT create memory unsafety, but the set will
+    ///report a length greater than the number of distinct values it holds, and the predicates comparing it to other sets may be wrong.
+    pub const unsafe fn from_array_unchecked(array: [T; LENGTH]) -> ArraySet<T, LENGTH> {
+        ArraySet { array }
+    }
+
+    pub const fn as_slice(&self) -> &[T] {
+        &self.array
+    }
+
+    ///Returns the number of elements in the ArraySet
+    pub const fn len(&self) -> usize {
+        LENGTH
+    }
+
+    ///Returns true if the store is empty, false otherwise.
+    pub const fn is_empty(&self) -> bool {
+        LENGTH == 0
+    }
+
+    ///Returns true if the set contains the given value. False otherwise.
+    ///The value may be any borrowed form of the set's value type.
+    pub fn contains<Q: ?Sized + Eq>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.array.iter().any(|v| v.borrow() == value)
+    }
+
+    pub fn into_inner(self) -> [T; LENGTH] {
+        self.array
+    }
+
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.array.iter()
+    }
+
+    ///Returns `true` if every value in `self` is also in `other`.
+    pub fn is_subset<const OTHER: usize>(&self, other: &ArraySet<T, OTHER>) -> bool {
+        LENGTH <= OTHER && self.iter().all(|v| other.contains(v))
+    }
+
+    ///Returns `true` if every value in `other` is also in `self`.
+    pub fn is_superset<const OTHER: usize>(&self, other: &ArraySet<T, OTHER>) -> bool {
+        other.is_subset(self)
+    }
+
+    ///Returns `true` if `self` and `other` hold equal values in the same order.
+    ///`==` ignores the order of the values.
+    pub fn eq_ordered<const OTHER: usize>(&self, other: &ArraySet<T, OTHER>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+
+    ///Returns `true` if `self` and `other` have no values in common.
+    pub fn is_disjoint<const OTHER: usize>(&self, other: &ArraySet<T, OTHER>) -> bool {
+        !self.iter().any(|v| other.contains(v))
+    }
+}
+
+impl<T: Eq, const LENGTH: usize, const OTHER: usize> PartialEq<ArraySet<T, OTHER>>
+    for ArraySet<T, LENGTH>
+{
+    ///Returns `true` if both sets hold the same values, in any order.
+    fn eq(&self, other: &ArraySet<T, OTHER>) -> bool {
+        LENGTH == OTHER && self.is_subset(other)
+    }
+}
+
+impl<T: Eq, const LENGTH: usize> Eq for ArraySet<T, LENGTH> {}
+
+impl<T: Eq + Hash, const LENGTH: usize> Hash for ArraySet<T, LENGTH> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        crate::std_traits::hash_unordered(self.iter(), state)
+    }
+}
+
+impl<T: Eq + Clone, const LENGTH: usize> TryClone for ArraySet<T, LENGTH> {
+    ///Never fails, as `ArraySet` does not allocate.
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(self.clone())
+    }
+}
+
+impl<T: Eq + fmt::Debug, const LENGTH: usize> fmt::Debug for ArraySet<T, LENGTH> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Eq, const LENGTH: usize> IntoIterator for ArraySet<T, LENGTH> {
+    type Item = T;
+
+    type IntoIter = std::array::IntoIter<T, LENGTH>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.array.into_iter()
+    }
+}
+
+impl<'a, T: Eq, const LENGTH: usize> IntoIterator for &'a ArraySet<T, LENGTH> {
+    type Item = &'a T;
+
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.array.iter()
+    }
+}
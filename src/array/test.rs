@@ -1,4 +1,4 @@
-use crate::array::map::ArrayMap;
+use crate::array::{map::ArrayMap, set::ArraySet};
 #[allow(unused_imports)]
 #[test]
 ///Since I keep forgetting this, if len is moved into the trait
@@ -33,3 +33,93 @@ fn from_array_unchecked_is_const() {
         let _ = unsafe { ArrayMap::from_array_unchecked([(1, 1)]) };
     }
 }
+
+#[test]
+///Will fail to compile if `len` on ArraySet is not const.
+fn array_set_len_is_const() {
+    const fn foo() -> usize {
+        let s = unsafe { ArraySet::from_array_unchecked([1, 2]) };
+
+        s.len()
+    }
+
+    assert_eq!(foo(), 2);
+}
+
+#[test]
+fn array_set_predicates() {
+    let small = unsafe { ArraySet::from_array_unchecked(["a", "b"]) };
+    let large = unsafe { ArraySet::from_array_unchecked(["c", "b", "a"]) };
+    let other = unsafe { ArraySet::from_array_unchecked(["d"]) };
+
+    assert!(large.contains("c"));
+    assert!(!small.contains("c"));
+
+    assert!(small.is_subset(&large));
+    assert!(!large.is_subset(&small));
+    assert!(large.is_superset(&small));
+    assert!(small.is_subset(&small));
+
+    assert!(small.is_disjoint(&other));
+    assert!(!small.is_disjoint(&large));
+
+    assert_eq!(
+        large.iter().copied().collect::<Vec<_>>(),
+        vec!["c", "b", "a"]
+    );
+    assert_eq!(small.into_inner(), ["a", "b"]);
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn array_set_macro() {
+    //the macro names the crate by path, as a consuming crate would.
+    use crate as linear_collections;
+
+    let set: ArraySet<char, 3> = crate::array::array_set!['A', 'B', 'C'];
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&'B'));
+    assert!(!set.contains(&'D'));
+}
+
+#[cfg(all(feature = "macros", not(miri)))]
+#[test]
+fn array_set_macro_rejects_invalid_input() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("src/test/should_panic/array_set_empty.rs");
+    t.compile_fail("src/test/should_panic/array_set_one_duplicate.rs");
+    t.compile_fail("src/test/should_panic/array_set_many_duplicates.rs");
+}
+
+#[test]
+fn array_map_const_lookups() {
+    const TABLE: ArrayMap<&str, u16, 3> =
+        unsafe { ArrayMap::from_array_unchecked([("http", 80), ("https", 443), ("ssh", 22)]) };
+    const HTTPS: u16 = match TABLE.const_get(&"https") {
+        Some(port) => *port,
+        None => 0,
+    };
+    //checked at compile time.
+    const _: () = assert!(!TABLE.const_contains_key(&"ftp"));
+
+    //usable wherever a const generic is expected.
+    let ports = [0u8; HTTPS as usize];
+
+    assert_eq!(ports.len(), 443);
+    assert_eq!(TABLE.const_index_of(&"ssh"), Some(2));
+    assert_eq!(TABLE.const_get(&"htt"), None);
+}
+
+#[test]
+fn array_map_const_lookups_on_bytes_and_chars() {
+    const BYTES: ArrayMap<&[u8], bool, 2> =
+        unsafe { ArrayMap::from_array_unchecked([(b"ab", true), (b"abc", false)]) };
+    const CHARS: ArrayMap<char, u8, 2> =
+        unsafe { ArrayMap::from_array_unchecked([('🦀', 1), ('a', 2)]) };
+
+    const ABC: Option<&bool> = BYTES.const_get(&b"abc".as_slice());
+    assert_eq!(ABC, Some(&false));
+    assert_eq!(BYTES.const_index_of(&b"a".as_slice()), None);
+    assert_eq!(CHARS.const_get(&'a'), Some(&2));
+    assert!(CHARS.const_contains_key(&'🦀'));
+}
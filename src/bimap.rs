@@ -0,0 +1,200 @@
+use std::{borrow::Borrow, error::Error};
+
+use crate::extend::{try_extend_with, TryExtendError};
+
+///The pairs displaced from a bidirectional map by an insertion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Overwritten<L, R> {
+    ///Neither value was present, so nothing was displaced.
+    Neither,
+    ///The left value was present, paired with a different right value. Holds the displaced pair.
+    Left(L, R),
+    ///The right value was present, paired with a different left value. Holds the displaced pair.
+    Right(L, R),
+    ///The exact pair was already present. Holds the displaced pair.
+    Pair(L, R),
+    ///The left and right values were each present in different pairs.
+    ///Holds the pair which matched on the left, then the pair which matched on the right.
+    Both((L, R), (L, R)),
+}
+
+impl<L, R> Overwritten<L, R> {
+    ///Returns true if the insertion displaced at least one pair.
+    pub const fn did_overwrite(&self) -> bool {
+        !matches!(self, Overwritten::Neither)
+    }
+}
+
+///Sealed trait providing positional access to the store backing a bidirectional map.
+pub(crate) trait BiMapIndexMut<L, R> {
+    ///SAFETY: UB if `index` is >= the length of the map.
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> &mut (L, R);
+
+    ///SAFETY: UB if `index` is >= the length of the map.
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (L, R);
+
+    ///Appends the pair to the backing store *without* checking if either value is already present.
+    ///Callers must guarantee that neither is, else the map will no longer be bidirectional.
+    fn push_unchecked(
+        &mut self,
+        left: L,
+        right: R,
+    ) -> Result<(), <Self as FallibleLinearBiMap<L, R>>::InsertionError>
+    where
+        L: Eq,
+        R: Eq,
+        Self: FallibleLinearBiMap<L, R>;
+}
+
+//sealed trait
+#[allow(private_bounds)]
+//Never implement clone: panics on alloc failure. Implement `TryClone` instead.
+///Provides methods for bidirectional maps backed by linear data structures, which can be searched from either side.
+///Both the left and the right values of every pair are unique within the map.
+pub trait FallibleLinearBiMap<L: Eq, R: Eq>: BiMapIndexMut<L, R> {
+    type Backing;
+    type InsertionError: Error;
+
+    ///Consumes self, returning the underlying store.
+    fn into_inner(self) -> Self::Backing;
+
+    ///Iterates over every pair in the map.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (L, R)>
+    where
+        L: 'a,
+        R: 'a;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///Inserts the pair into the map, displacing any pairs which share its left or its right value.
+    ///Returns which pairs were displaced. A displacing insertion reuses the space of a displaced pair, so only
+    ///an insertion which returns `Overwritten::Neither` can fail.
+    fn insert(&mut self, left: L, right: R) -> Result<Overwritten<L, R>, Self::InsertionError> {
+        let left_index = self.iter().position(|(l, _)| *l == left);
+        let right_index = self.iter().position(|(_, r)| *r == right);
+
+        //SAFETY: `position` only returns indices of pairs within the map.
+        unsafe {
+            match (left_index, right_index) {
+                (None, None) => self
+                    .push_unchecked(left, right)
+                    .map(|_| Overwritten::Neither),
+                (Some(l), None) => {
+                    let (ol, or) =
+                        std::mem::replace(self.get_index_unchecked_mut(l), (left, right));
+                    Ok(Overwritten::Left(ol, or))
+                }
+                (None, Some(r)) => {
+                    let (ol, or) =
+                        std::mem::replace(self.get_index_unchecked_mut(r), (left, right));
+                    Ok(Overwritten::Right(ol, or))
+                }
+                (Some(l), Some(r)) => match l == r {
+                    true => {
+                        let (ol, or) =
+                            std::mem::replace(self.get_index_unchecked_mut(l), (left, right));
+                        Ok(Overwritten::Pair(ol, or))
+                    }
+                    false => {
+                        let left_pair =
+                            std::mem::replace(self.get_index_unchecked_mut(l), (left, right));
+                        //the replacement above does not move any pair, so `r` is still in bounds.
+                        let right_pair = self.remove_index_unchecked(r);
+                        Ok(Overwritten::Both(left_pair, right_pair))
+                    }
+                },
+            }
+        }
+    }
+
+    ///Gets the right value paired with the given left value.
+    ///Will return None if that left value is not in the map.
+    fn get_by_left<'a, Q: ?Sized + Eq>(&'a self, left: &Q) -> Option<&'a R>
+    where
+        L: 'a + Borrow<Q>,
+        R: 'a,
+    {
+        self.iter()
+            .find(|(l, _)| l.borrow() == left)
+            .map(|(_, r)| r)
+    }
+
+    ///Gets the left value paired with the given right value.
+    ///Will return None if that right value is not in the map.
+    fn get_by_right<'a, Q: ?Sized + Eq>(&'a self, right: &Q) -> Option<&'a L>
+    where
+        L: 'a,
+        R: 'a + Borrow<Q>,
+    {
+        self.iter()
+            .find(|(_, r)| r.borrow() == right)
+            .map(|(l, _)| l)
+    }
+
+    ///Returns true if this map contains the given left value. False otherwise.
+    fn contains_left<Q: ?Sized + Eq>(&self, left: &Q) -> bool
+    where
+        L: Borrow<Q>,
+    {
+        self.iter().any(|(l, _)| l.borrow() == left)
+    }
+
+    ///Returns true if this map contains the given right value. False otherwise.
+    fn contains_right<Q: ?Sized + Eq>(&self, right: &Q) -> bool
+    where
+        R: Borrow<Q>,
+    {
+        self.iter().any(|(_, r)| r.borrow() == right)
+    }
+
+    ///Tries to remove the pair with the given left value, returning None if it is not found.
+    fn remove_by_left<Q: ?Sized + Eq>(&mut self, left: &Q) -> Option<(L, R)>
+    where
+        L: Borrow<Q>,
+    {
+        let index = self.iter().position(|(l, _)| l.borrow() == left)?;
+        //SAFETY: `position` only returns indices of pairs within the map.
+        Some(unsafe { self.remove_index_unchecked(index) })
+    }
+
+    ///Tries to remove the pair with the given right value, returning None if it is not found.
+    fn remove_by_right<Q: ?Sized + Eq>(&mut self, right: &Q) -> Option<(L, R)>
+    where
+        R: Borrow<Q>,
+    {
+        let index = self.iter().position(|(_, r)| r.borrow() == right)?;
+        //SAFETY: `position` only returns indices of pairs within the map.
+        Some(unsafe { self.remove_index_unchecked(index) })
+    }
+
+    ///Iterates over the left values of the map.
+    fn lefts<'a>(&'a self) -> impl Iterator<Item = &'a L>
+    where
+        L: 'a,
+        R: 'a,
+    {
+        self.iter().map(|(l, _)| l)
+    }
+
+    ///Iterates over the right values of the map.
+    fn rights<'a>(&'a self) -> impl Iterator<Item = &'a R>
+    where
+        L: 'a,
+        R: 'a,
+    {
+        self.iter().map(|(_, r)| r)
+    }
+
+    ///Inserts every pair of `iter` into the map, displacing pairs as `insert` does.
+    ///On failure, the pairs inserted before the error remain in the map.
+    fn try_extend<I: IntoIterator<Item = (L, R)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryExtendError<Self::InsertionError>> {
+        try_extend_with(iter.into_iter(), |(l, r)| self.insert(l, r).map(drop))
+    }
+}
@@ -0,0 +1,21 @@
+///Wraps an iterator which extracts items from a collection, exhausting it when dropped.
+///This guarantees every item it would yield is removed even if the caller stops iterating early, like `Vec::drain`.
+pub(crate) struct Drain<I: Iterator>(pub(crate) I);
+
+impl<I: Iterator> Iterator for Drain<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I: Iterator> Drop for Drain<I> {
+    fn drop(&mut self) {
+        self.0.by_ref().for_each(drop);
+    }
+}
@@ -0,0 +1,204 @@
+use std::marker::PhantomData;
+
+use crate::FallibleLinearMap;
+
+///A view into a single entry in a map, which may either be vacant or occupied.
+///
+///Constructed by `FallibleLinearMap::entry`. The map is scanned exactly once when the entry is created:
+///every method on the entry works from the position found by that scan.
+pub enum Entry<'a, K: Eq, V, M: FallibleLinearMap<K, V>> {
+    Occupied(OccupiedEntry<'a, K, V, M>),
+    Vacant(VacantEntry<'a, K, V, M>),
+}
+
+impl<'a, K: Eq + 'a, V: 'a, M: FallibleLinearMap<K, V>> Entry<'a, K, V, M> {
+    ///Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(o) => o.key(),
+            Entry::Vacant(v) => v.key(),
+        }
+    }
+
+    ///Ensures a value is in the entry by inserting `default` if it is vacant, returning an exclusive
+    ///reference to the value in the entry.
+    ///Returns an error if the map could not make room for the new pair.
+    pub fn or_try_insert(self, default: V) -> Result<&'a mut V, M::InsertionError> {
+        match self {
+            Entry::Occupied(o) => Ok(o.into_mut()),
+            Entry::Vacant(v) => v.try_insert(default),
+        }
+    }
+
+    ///Ensures a value is in the entry by inserting the result of `default` if it is vacant, returning an exclusive
+    ///reference to the value in the entry. `default` is only called if the entry is vacant.
+    ///Returns an error if the map could not make room for the new pair.
+    pub fn or_try_insert_with<F: FnOnce() -> V>(
+        self,
+        default: F,
+    ) -> Result<&'a mut V, M::InsertionError> {
+        match self {
+            Entry::Occupied(o) => Ok(o.into_mut()),
+            Entry::Vacant(v) => v.try_insert(default()),
+        }
+    }
+
+    ///Provides exclusive access to an occupied entry's value before any potential inserts into the map.
+    ///Does nothing if the entry is vacant.
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut o) => {
+                f(o.get_mut());
+                Entry::Occupied(o)
+            }
+            Entry::Vacant(v) => Entry::Vacant(v),
+        }
+    }
+
+    ///Sets the value of the entry, whether or not it was occupied, and returns the now occupied entry.
+    ///Returns an error if the entry was vacant and the map could not make room for the new pair.
+    pub fn try_insert(self, value: V) -> Result<OccupiedEntry<'a, K, V, M>, M::InsertionError> {
+        match self {
+            Entry::Occupied(mut o) => {
+                o.insert(value);
+                Ok(o)
+            }
+            Entry::Vacant(v) => v.try_insert_entry(value),
+        }
+    }
+}
+
+#[cfg(feature = "panicking")]
+#[allow(private_bounds)]
+impl<'a, K: Eq + 'a, V: 'a, M: FallibleLinearMap<K, V>> Entry<'a, K, V, M>
+where
+    M::InsertionError: crate::panicking::InsertionFailure,
+{
+    ///Ensures a value is in the entry by inserting `default` if it is vacant, returning an exclusive
+    ///reference to the value in the entry.
+    ///Aborts or panics if the map could not make room for the new pair, see `InfallibleLinearMap::insert`.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        use crate::panicking::InsertionFailure;
+
+        self.or_try_insert(default).unwrap_or_else(|e| e.fail())
+    }
+
+    ///Ensures a value is in the entry by inserting the result of `default` if it is vacant, returning an exclusive
+    ///reference to the value in the entry. `default` is only called if the entry is vacant.
+    ///Aborts or panics if the map could not make room for the new pair, see `InfallibleLinearMap::insert`.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        use crate::panicking::InsertionFailure;
+
+        self.or_try_insert_with(default)
+            .unwrap_or_else(|e| e.fail())
+    }
+}
+
+///A view into an occupied entry in a map. It is part of the `Entry` enum.
+pub struct OccupiedEntry<'a, K: Eq, V, M: FallibleLinearMap<K, V>> {
+    map: &'a mut M,
+    ///The position of the entry's pair within the map's backing store.
+    ///This is always within the bounds of the map: the entry holds an exclusive borrow
+    ///of the map so nothing can remove it from underneath us.
+    index: usize,
+    phantom: PhantomData<(K, V)>,
+}
+
+impl<'a, K: Eq + 'a, V: 'a, M: FallibleLinearMap<K, V>> OccupiedEntry<'a, K, V, M> {
+    ///SAFETY: UB if `index` is not within the bounds of `map`.
+    pub(crate) unsafe fn new(map: &'a mut M, index: usize) -> Self {
+        Self {
+            map,
+            index,
+            phantom: PhantomData,
+        }
+    }
+
+    ///Returns a reference to the key in the entry.
+    pub fn key(&self) -> &K {
+        //SAFETY: `index` is in bounds for as long as this entry exists.
+        unsafe { self.map.get_index_unchecked(self.index) }.0
+    }
+
+    ///Returns a shared reference to the value in the entry.
+    pub fn get(&self) -> &V {
+        //SAFETY: `index` is in bounds for as long as this entry exists.
+        unsafe { self.map.get_index_unchecked(self.index) }.1
+    }
+
+    ///Returns an exclusive reference to the value in the entry.
+    ///If you need a reference which outlives the entry, see `into_mut`.
+    pub fn get_mut(&mut self) -> &mut V {
+        //SAFETY: `index` is in bounds for as long as this entry exists.
+        unsafe { self.map.get_index_unchecked_mut(self.index) }.1
+    }
+
+    ///Converts the entry into an exclusive reference to its value, bound to the lifetime of the map.
+    pub fn into_mut(self) -> &'a mut V {
+        //SAFETY: `index` is in bounds for as long as this entry exists.
+        unsafe { self.map.get_index_unchecked_mut(self.index) }.1
+    }
+
+    ///Sets the value of the entry, returning the old value.
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    ///Takes the key-value pair out of the map.
+    pub fn remove_entry(self) -> (K, V) {
+        //SAFETY: `index` is in bounds for as long as this entry exists.
+        unsafe { self.map.remove_index_unchecked(self.index) }
+    }
+
+    ///Takes the value out of the map.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+///A view into a vacant entry in a map. It is part of the `Entry` enum.
+pub struct VacantEntry<'a, K: Eq, V, M: FallibleLinearMap<K, V>> {
+    map: &'a mut M,
+    key: K,
+    phantom: PhantomData<V>,
+}
+
+impl<'a, K: Eq + 'a, V: 'a, M: FallibleLinearMap<K, V>> VacantEntry<'a, K, V, M> {
+    ///The caller must have checked that `key` is not present in `map`.
+    pub(crate) fn new(map: &'a mut M, key: K) -> Self {
+        Self {
+            map,
+            key,
+            phantom: PhantomData,
+        }
+    }
+
+    ///Returns a reference to the key that would be used when inserting through this entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    ///Takes ownership of the key.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    ///Sets the value of the entry with the entry's key, returning an exclusive reference to the value.
+    ///Returns an error if the map could not make room for the new pair.
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, M::InsertionError> {
+        self.try_insert_entry(value).map(|o| o.into_mut())
+    }
+
+    ///Sets the value of the entry with the entry's key, returning the now occupied entry.
+    ///Returns an error if the map could not make room for the new pair.
+    pub fn try_insert_entry(
+        self,
+        value: V,
+    ) -> Result<OccupiedEntry<'a, K, V, M>, M::InsertionError> {
+        //we scanned for the key when creating this entry, so it's guaranteed not to be present.
+        let index = self.map.push_unchecked(self.key, value)?;
+
+        //SAFETY: `push_unchecked` returns the index of the pair it just added.
+        Ok(unsafe { OccupiedEntry::new(self.map, index) })
+    }
+}
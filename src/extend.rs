@@ -0,0 +1,64 @@
+use std::{error::Error, fmt::Display};
+
+///Conversion from an iterator for collections whose insertions can fail.
+///This is the fallible counterpart of `FromIterator`, which we can't implement as it has no way to report a failed allocation.
+pub trait TryFromIterator<A>: Sized {
+    type Error;
+
+    ///Creates a collection from the items of `iter`, following the collection's insertion semantics.
+    fn try_from_iter<I: IntoIterator<Item = A>>(iter: I) -> Result<Self, Self::Error>;
+}
+
+///The error returned when extending a collection from an iterator fails part way through.
+///Everything consumed before the failure remains in the collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryExtendError<E> {
+    error: E,
+    consumed: usize,
+}
+
+impl<E> TryExtendError<E> {
+    pub(crate) fn new(error: E, consumed: usize) -> Self {
+        Self { error, consumed }
+    }
+
+    ///The error returned by the insertion which failed.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    ///Consumes self, returning the error returned by the insertion which failed.
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    ///The number of items taken from the iterator, including the item which could not be inserted.
+    ///If the collection could not reserve room up front, this is 0.
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+}
+
+impl<E: Display> Display for TryExtendError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} after consuming {} items", self.error, self.consumed)
+    }
+}
+
+impl<E: Error + 'static> Error for TryExtendError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+///Inserts every item of `iter` with `insert`, counting how many items were consumed.
+pub(crate) fn try_extend_with<A, E>(
+    iter: impl Iterator<Item = A>,
+    mut insert: impl FnMut(A) -> Result<(), E>,
+) -> Result<(), TryExtendError<E>> {
+    for (index, item) in iter.enumerate() {
+        insert(item).map_err(|e| TryExtendError::new(e, index + 1))?;
+    }
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
-use crate::{FallibleLinearMap, MapIterMut};
-use std::collections::TryReserveError;
+use crate::{
+    policy::Reorder, search::KeySearch, FallibleLinearMap, LinearMapValuesMut, LinearMapView,
+    MapExtract, MapIndex, MapIndexMut, MapIterMut, PositionalLinearMap, TryClone, TryExtendError,
+    TryFromIterator,
+};
+use std::{borrow::Borrow, collections::TryReserveError};
 
 use super::FatVec;
 
-#[derive(Eq, PartialEq, Debug, Hash)]
 ///A map type backed by an FatVec, a vector with stack space to hold up to
 ///`STACK_CAPACITY` items on the stack. The remaining overflow onto the heap.
 pub struct FatMap<K: Eq, V, const STACK_CAPACITY: usize> {
@@ -82,39 +85,177 @@ impl<K: Eq, V, const STACK_CAPACITY: usize> FallibleLinearMap<K, V>
         self.fatvec
     }
 
-    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (K, V)>
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
     where
-        K: 'a,
-        V: 'a,
+        K: Borrow<Q>,
     {
-        self.fatvec.iter()
-    }
-
-    fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
         let idx = self
             .fatvec
             .iter()
             .enumerate()
-            .find(|(_, (k, _))| k == key)
+            .find(|(_, (k, _))| k.borrow() == key)
             .map(|(i, _)| i)?;
 
         self.fatvec.remove(idx)
     }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> LinearMapView<K, V> for FatMap<K, V, STACK_CAPACITY> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.fatvec.iter().map(|(k, v)| (k, v))
+    }
 
     fn len(&self) -> usize {
         self.fatvec.len()
     }
 }
 
+impl<K: Eq, V, const STACK_CAPACITY: usize> LinearMapValuesMut<K, V>
+    for FatMap<K, V, STACK_CAPACITY>
+{
+}
+
 impl<K: Eq, V, const STACK_CAPACITY: usize> MapIterMut<K, V> for FatMap<K, V, STACK_CAPACITY> {
-    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut (K, V)>
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
     where
         K: 'a,
         V: 'a,
     {
-        self.fatvec.iter_mut()
+        self.fatvec.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> MapExtract<K, V> for FatMap<K, V, STACK_CAPACITY> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.fatvec.extract_if(move |(k, v)| pred(k, v))
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> MapIndexMut<K, V> for FatMap<K, V, STACK_CAPACITY> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //SAFETY: upheld by caller
+        unsafe { self.fatvec.remove_unchecked(index) }
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.fatvec.push((key, value))?;
+        Ok(self.fatvec.len() - 1)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.fatvec.reserve(additional)
+    }
+
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.fatvec.reserve_total(additional)
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> MapIndex<K, V> for FatMap<K, V, STACK_CAPACITY> {
+    fn index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        //the pairs on the heap are only searched once every pair on the stack has been.
+        let (stack, heap) = self.fatvec.as_slices();
+
+        K::position(stack, key).or_else(|| K::position(heap, key).map(|i| stack.len() + i))
+    }
+
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.fatvec.get_unchecked(index) };
+        (k, v)
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.fatvec.get_unchecked_mut(index) };
+        (k, v)
     }
 }
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> Reorder for FatMap<K, V, STACK_CAPACITY> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        //SAFETY: upheld by caller
+        unsafe { Reorder::swap_unchecked(&mut self.fatvec, a, b) }
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //SAFETY: upheld by caller
+        unsafe { self.fatvec.move_to_front_unchecked(index) }
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> PositionalLinearMap<K, V>
+    for FatMap<K, V, STACK_CAPACITY>
+{
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> Default for FatMap<K, V, STACK_CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> TryFromIterator<(K, V)>
+    for FatMap<K, V, STACK_CAPACITY>
+{
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Eq + Clone, V: Clone, const STACK_CAPACITY: usize> TryClone
+    for FatMap<K, V, STACK_CAPACITY>
+{
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.fatvec.try_clone().map(|fatvec| FatMap { fatvec })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.fatvec.try_clone_from(&source.fatvec)
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> IntoIterator for FatMap<K, V, STACK_CAPACITY> {
+    type Item = (K, V);
+
+    type IntoIter = crate::FatVecIterator<(K, V), STACK_CAPACITY>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fatvec.into_iter()
+    }
+}
+
+crate::std_traits::map_traits!([K: Eq, V, const STACK_CAPACITY: usize] FatMap<K, V, STACK_CAPACITY>);
+crate::std_traits::map_index!([K: Eq, V, const STACK_CAPACITY: usize] FatMap<K, V, STACK_CAPACITY>);
+
 #[cfg(feature = "serde")]
 impl<
         'a,
@@ -131,6 +272,22 @@ impl<
     }
 }
 
+#[cfg(feature = "serde")]
+impl<
+        'de,
+        K: Eq + serde::Deserialize<'de>,
+        V: serde::Deserialize<'de>,
+        const STACK_CAPACITY: usize,
+    > serde::Deserialize<'de> for FatMap<K, V, STACK_CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
+
 /*
 pub struct IntoIterator<T, const STACK_CAPACITY: usize> {
     fv: FatVec<MaybeUninit<T>, STACK_CAPACITY>,
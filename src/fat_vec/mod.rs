@@ -4,14 +4,27 @@ mod serde;
 #[cfg(test)]
 pub mod test;
 
-use crate::stack_list::RawStackList;
+use crate::{
+    extend::{try_extend_with, TryExtendError},
+    policy::Reorder,
+    soa::Column,
+    stack_list::RawStackList,
+    TryClone, TryFromIterator,
+};
 use std::{
-    array, collections::TryReserveError, hash::Hash, intrinsics::transmute_unchecked,
+    array,
+    collections::TryReserveError,
+    hash::Hash,
+    intrinsics::transmute_unchecked,
     mem::MaybeUninit,
+    ptr::{copy, copy_nonoverlapping},
 };
 
 pub mod map;
+pub mod multimap;
 pub mod set;
+pub mod soa_map;
+pub mod sorted_map;
 
 #[derive(Debug)]
 ///A vector which allocates at least `STACK_CAPACITY` elements onto the stack.
@@ -211,7 +224,7 @@ impl<const STACK_CAPACITY: usize, T> FatVec<T, STACK_CAPACITY> {
     ///SAFETY:
     ///Undefined Behavior if `idx` is greater than or equal to the length of this `FatVec`.
     pub unsafe fn remove_unchecked(&mut self, idx: usize) -> T {
-        let r = match idx <= STACK_CAPACITY {
+        let r = match STACK_CAPACITY > idx {
             //value is resident on stack
             true => {
                 //SAFETY
@@ -246,6 +259,50 @@ impl<const STACK_CAPACITY: usize, T> FatVec<T, STACK_CAPACITY> {
         r
     }
 
+    ///Inserts `value` at `idx`, shifting every element after it right by one.
+    ///If the stack is full, its last element is moved onto the front of the heap to make room.
+    ///SAFETY:
+    ///UB if `idx` is greater than the length of this `FatVec`.
+    pub(crate) unsafe fn insert_unchecked(
+        &mut self,
+        idx: usize,
+        value: T,
+    ) -> Result<(), TryReserveError> {
+        match STACK_CAPACITY > idx {
+            //value belongs on the stack
+            true => match STACK_CAPACITY > self.len {
+                //SAFETY: upheld by caller, and there is room on the stack to shift into.
+                true => unsafe { self.stack_list.insert_shift(idx, self.len, value) },
+                false => {
+                    //reserve before moving anything, so that a failure leaves us untouched.
+                    if self.vec.len() == self.vec.capacity() {
+                        self.reserve(1)?;
+                    }
+
+                    //SAFETY: the stack is full, so its last slot is initialized. Removing it leaves
+                    //room to shift the rest of the stack into.
+                    unsafe {
+                        let last = self.stack_list.remove(STACK_CAPACITY - 1, STACK_CAPACITY);
+                        self.stack_list
+                            .insert_shift(idx, STACK_CAPACITY - 1, value);
+                        self.vec.insert(0, last);
+                    }
+                }
+            },
+            //value belongs on the heap
+            false => {
+                if self.vec.len() == self.vec.capacity() {
+                    self.reserve(1)?;
+                }
+
+                self.vec.insert(idx - STACK_CAPACITY, value);
+            }
+        }
+
+        self.len += 1;
+        Ok(())
+    }
+
     ///Returns a shared reference to the item at the requested, returning `None` if idx is outside the range of the `FatVec`.
     pub fn get(&self, idx: usize) -> Option<&T> {
         if idx >= self.len {
@@ -309,12 +366,236 @@ impl<const STACK_CAPACITY: usize, T> FatVec<T, STACK_CAPACITY> {
         self.vec.try_reserve_exact(additional).map_err(|e| e.into())
     }
 
+    ///Removes and yields every element for which `pred` returns true, shifting the kept elements left in a single pass.
+    ///Elements which have not been visited when the iterator is dropped are kept.
+    pub(crate) fn extract_if<F: FnMut(&mut T) -> bool>(
+        &mut self,
+        pred: F,
+    ) -> ExtractIf<'_, T, STACK_CAPACITY, F> {
+        let original_len = self.len;
+
+        //If the iterator is leaked the elements are leaked with it, rather than being dropped twice.
+        self.len = 0;
+        //SAFETY: 0 is always a valid length, and the heap elements are still tracked by `original_len`.
+        unsafe { self.vec.set_len(0) };
+
+        ExtractIf {
+            fatvec: self,
+            pred,
+            read: 0,
+            write: 0,
+            original_len,
+        }
+    }
+
+    ///Returns the elements on the stack and the elements on the heap, in that order.
+    pub(crate) fn as_slices(&self) -> (&[T], &[T]) {
+        //SAFETY: every slot on the stack below `array_len` is initialized.
+        let stack = unsafe { std::slice::from_raw_parts(self.stack_list.as_ptr(), self.array_len()) };
+
+        (stack, self.vec.as_slice())
+    }
+
+    ///Returns raw pointers to the first slot on the stack and the first slot on the heap, in that order.
+    ///Neither slot is guaranteed to be initialized.
+    fn slot_ptrs(&mut self) -> (*mut T, *mut T) {
+        (self.stack_list.as_mut_ptr(), self.vec.as_mut_ptr())
+    }
+
+    ///Reserves room for at least `additional` more elements, only allocating for those which will not fit on the stack.
+    pub(crate) fn reserve_total(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let overflow = self
+            .len
+            .saturating_add(additional)
+            .saturating_sub(STACK_CAPACITY)
+            .saturating_sub(self.vec.len());
+
+        match overflow {
+            0 => Ok(()),
+            overflow => self.reserve(overflow),
+        }
+    }
+
+    ///Appends every element of `iter` to this `FatVec`. Room is reserved once from the iterator's `size_hint`.
+    ///On failure, the elements pushed before the error remain in the `FatVec`.
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryExtendError<TryReserveError>> {
+        let iter = iter.into_iter();
+
+        self.reserve_total(iter.size_hint().0)
+            .map_err(|e| TryExtendError::new(e, 0))?;
+
+        try_extend_with(iter, |t| self.push(t))
+    }
+
     ///Shrinks the heap storage of this `FatVec` to match capacity.
     pub fn shrink_to_fit(&mut self) {
         self.vec.shrink_to_fit()
     }
 }
 
+impl<T, const STACK_CAPACITY: usize> Reorder for FatVec<T, STACK_CAPACITY> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        let slots = self.slot_ptrs();
+        //SAFETY: both indices are below the length as upheld by the caller, so both slots are initialized.
+        unsafe {
+            std::ptr::swap(
+                slot::<T, STACK_CAPACITY>(slots, a),
+                slot::<T, STACK_CAPACITY>(slots, b),
+            )
+        }
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //the elements are split between the stack and the heap, so they can't be rotated as one slice.
+        for i in (0..index).rev() {
+            //SAFETY: i < i + 1 <= index, which is in bounds as upheld by the caller.
+            unsafe { self.swap_unchecked(i, i + 1) }
+        }
+    }
+}
+
+impl<T, const STACK_CAPACITY: usize> Column for FatVec<T, STACK_CAPACITY> {
+    type Item = T;
+    type Slots = (*mut T, *mut T);
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn set_len(&mut self, len: usize) {
+        self.len = len;
+        //SAFETY: upheld by caller
+        unsafe { self.vec.set_len(len.saturating_sub(STACK_CAPACITY)) };
+    }
+
+    fn slots(&mut self) -> (*mut T, *mut T) {
+        self.slot_ptrs()
+    }
+
+    unsafe fn slot(slots: (*mut T, *mut T), index: usize) -> *mut T {
+        //SAFETY: upheld by caller
+        unsafe { slot::<T, STACK_CAPACITY>(slots, index) }
+    }
+}
+
+impl<T: Clone, const STACK_CAPACITY: usize> TryClone for FatVec<T, STACK_CAPACITY> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        //clone the heap first, so we don't leak the cloned stack elements if it fails.
+        let vec = self.vec.try_clone()?;
+
+        Ok(Self {
+            //SAFETY: array_len is bound by the initialized elements of the stack.
+            stack_list: unsafe { self.stack_list.clone_to(self.array_len()) },
+            vec,
+            len: self.len,
+        })
+    }
+}
+
+impl<T, const STACK_CAPACITY: usize> TryFromIterator<T> for FatVec<T, STACK_CAPACITY> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        let mut fatvec = Self::new();
+        fatvec.try_extend(iter)?;
+        Ok(fatvec)
+    }
+}
+
+///Iterator returned by `FatVec::extract_if`.
+pub(crate) struct ExtractIf<'a, T, const STACK_CAPACITY: usize, F: FnMut(&mut T) -> bool> {
+    fatvec: &'a mut FatVec<T, STACK_CAPACITY>,
+    pred: F,
+    ///Index of the next element to test.
+    read: usize,
+    ///Number of elements kept so far. These have all been shifted to the front of the `FatVec`.
+    write: usize,
+    ///The length of the `FatVec` before extraction began.
+    original_len: usize,
+}
+
+impl<'a, T, const STACK_CAPACITY: usize, F: FnMut(&mut T) -> bool> Iterator
+    for ExtractIf<'a, T, STACK_CAPACITY, F>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.read < self.original_len {
+            let slots = self.fatvec.slot_ptrs();
+
+            //SAFETY: everything in `read..original_len` is initialized and untouched.
+            let current = unsafe { slot::<T, STACK_CAPACITY>(slots, self.read) };
+            let extract = (self.pred)(unsafe { &mut *current });
+
+            self.read += 1;
+
+            match extract {
+                //SAFETY: `read` has moved past this slot, so it will never be read again.
+                true => return Some(unsafe { current.read() }),
+                false => {
+                    //SAFETY: `write` < `read`, so the slot at `write` has either been moved out of or is this slot.
+                    //Elements may cross from the heap onto the stack, so we move them one at a time.
+                    unsafe { copy(current, slot::<T, STACK_CAPACITY>(slots, self.write), 1) };
+                    self.write += 1;
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.original_len - self.read))
+    }
+}
+
+impl<'a, T, const STACK_CAPACITY: usize, F: FnMut(&mut T) -> bool> Drop
+    for ExtractIf<'a, T, STACK_CAPACITY, F>
+{
+    fn drop(&mut self) {
+        let tail = self.original_len - self.read;
+
+        //shift the unvisited elements left to sit directly after the kept elements.
+        if self.read != self.write {
+            let slots = self.fatvec.slot_ptrs();
+
+            for offset in 0..tail {
+                //SAFETY: both indices are below `original_len`, and the destination has been moved out of.
+                unsafe {
+                    copy_nonoverlapping(
+                        slot::<T, STACK_CAPACITY>(slots, self.read + offset),
+                        slot::<T, STACK_CAPACITY>(slots, self.write + offset),
+                        1,
+                    )
+                };
+            }
+        }
+
+        let len = self.write + tail;
+
+        self.fatvec.len = len;
+        //SAFETY: every element in the heap up to this length is initialized and within capacity.
+        unsafe { self.fatvec.vec.set_len(len.saturating_sub(STACK_CAPACITY)) };
+    }
+}
+
+///Offsets into the pointers returned by `FatVec::slot_ptrs`.
+///SAFETY:
+///UB if `idx` is beyond the capacity of the `FatVec`.
+unsafe fn slot<T, const STACK_CAPACITY: usize>(
+    (stack, heap): (*mut T, *mut T),
+    idx: usize,
+) -> *mut T {
+    match STACK_CAPACITY > idx {
+        //SAFETY: upheld by caller.
+        true => unsafe { stack.add(idx) },
+        false => unsafe { heap.add(idx - STACK_CAPACITY) },
+    }
+}
+
 impl<const STACK_CAPACITY: usize, T: PartialEq> PartialEq for FatVec<T, STACK_CAPACITY> {
     fn eq(&self, other: &Self) -> bool {
         //just want to explicitly evaluate this first as it's much cheaper.
@@ -0,0 +1,118 @@
+use std::collections::TryReserveError;
+
+use crate::{
+    FallibleLinearMultiMap, MapExtract, MapIterMut, TryClone, TryExtendError, TryFromIterator,
+};
+
+use super::FatVec;
+
+///A multimap backed by a FatVec: a map which may hold any number of values for the same key, in insertion order.
+///The first `STACK_CAPACITY` pairs are held on the stack, the remaining overflow onto the heap.
+#[derive(Eq, PartialEq, Debug, Hash)]
+pub struct FatMultiMap<K: Eq, V, const STACK_CAPACITY: usize> {
+    fatvec: FatVec<(K, V), STACK_CAPACITY>,
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> FatMultiMap<K, V, STACK_CAPACITY> {
+    ///Creates a new, empty `FatMultiMap`. Without allocating on the heap.
+    pub fn new() -> Self {
+        Self {
+            fatvec: FatVec::new(),
+        }
+    }
+
+    ///Creates a new, empty `FatMultiMap` with space to hold at least `capacity` pairs without reallocating.
+    ///If `capacity` is less than or equal to `STACK_CAPACITY` the total capacity will be equal to `STACK_CAPACITY`.
+    pub fn with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        FatVec::with_capacity(capacity).map(|fatvec| Self { fatvec })
+    }
+
+    ///Creates a new `FatMultiMap` from the supplied `FatVec`.
+    ///Unlike the other maps in this crate, repeated keys are permitted so this cannot violate any invariant.
+    pub const fn from_fatvec(fatvec: FatVec<(K, V), STACK_CAPACITY>) -> Self {
+        Self { fatvec }
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> Default for FatMultiMap<K, V, STACK_CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> FallibleLinearMultiMap<K, V>
+    for FatMultiMap<K, V, STACK_CAPACITY>
+{
+    type Backing = FatVec<(K, V), STACK_CAPACITY>;
+    type InsertionError = TryReserveError;
+
+    fn insert(&mut self, key: K, value: V) -> Result<(), Self::InsertionError> {
+        self.fatvec.push((key, value))
+    }
+
+    fn into_inner(self) -> Self::Backing {
+        self.fatvec
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (K, V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.fatvec.iter()
+    }
+
+    fn len(&self) -> usize {
+        self.fatvec.len()
+    }
+
+    fn try_extend<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryExtendError<Self::InsertionError>> {
+        self.fatvec.try_extend(iter)
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> MapIterMut<K, V> for FatMultiMap<K, V, STACK_CAPACITY> {
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.fatvec.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> MapExtract<K, V> for FatMultiMap<K, V, STACK_CAPACITY> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.fatvec.extract_if(move |(k, v)| pred(k, v))
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> TryFromIterator<(K, V)>
+    for FatMultiMap<K, V, STACK_CAPACITY>
+{
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Eq + Clone, V: Clone, const STACK_CAPACITY: usize> TryClone
+    for FatMultiMap<K, V, STACK_CAPACITY>
+{
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.fatvec.try_clone().map(|fatvec| Self { fatvec })
+    }
+}
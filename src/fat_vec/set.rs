@@ -1,10 +1,9 @@
 use std::collections::TryReserveError;
 
-use crate::FallibleLinearSet;
+use crate::{FallibleLinearSet, TryClone, TryExtendError, TryFromIterator};
 
 use super::map::FatMap;
 
-#[derive(Debug, PartialEq, Eq, Hash)]
 ///A set type backed by a FatVec, a vector with stack space to hold up to
 ///`STACK_CAPACITY` items on the stack. The remaining items overflow onto the heap.
 pub struct FatSet<T: Eq, const STACK_CAPACITY: usize> {
@@ -56,6 +55,34 @@ impl<T: Eq, const STACK_CAPACITY: usize> FallibleLinearSet<T> for FatSet<T, STAC
     }
 }
 
+impl<T: Eq, const STACK_CAPACITY: usize> Default for FatSet<T, STACK_CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq, const STACK_CAPACITY: usize> TryFromIterator<T> for FatSet<T, STACK_CAPACITY> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        let mut set = Self::new();
+        set.try_extend(iter)?;
+        Ok(set)
+    }
+}
+
+impl<T: Eq + Clone, const STACK_CAPACITY: usize> TryClone for FatSet<T, STACK_CAPACITY> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.map.try_clone().map(|map| FatSet { map })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.map.try_clone_from(&source.map)
+    }
+}
+
+crate::std_traits::set_traits!([T: Eq, const STACK_CAPACITY: usize] FatSet<T, STACK_CAPACITY>);
+
 #[cfg(feature = "serde")]
 impl<'a, T: Eq + serde::Serialize, const STACK_CAPACITY: usize> serde::Serialize
     for FatSet<T, STACK_CAPACITY>
@@ -67,3 +94,15 @@ impl<'a, T: Eq + serde::Serialize, const STACK_CAPACITY: usize> serde::Serialize
         crate::serde::serialize_fallible_set(self, serializer)
     }
 }
+
+#[cfg(feature = "serde")]
+impl<'de, T: Eq + serde::Deserialize<'de>, const STACK_CAPACITY: usize> serde::Deserialize<'de>
+    for FatSet<T, STACK_CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_set(deserializer)
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{
+    policy::Reorder, search::KeySearch, soa::ExtractPairs, FallibleLinearMap, LinearMapValuesMut,
+    LinearMapView, MapExtract, MapIndex, MapIndexMut, MapIterMut, PositionalLinearMap, TryClone,
+    TryExtendError, TryFromIterator,
+};
+use std::{borrow::Borrow, collections::TryReserveError};
+
+use super::FatVec;
+
+///A map backed by two `FatVec`s: one holding the keys and the other their values, in the same order.
+///Up to `STACK_CAPACITY` pairs are held on the stack, the remaining overflow onto the heap.
+///Searching the map only touches the keys, so lookups stay cache friendly when the values are large.
+pub struct SoaFatMap<K: Eq, V, const STACK_CAPACITY: usize> {
+    keys: FatVec<K, STACK_CAPACITY>,
+    values: FatVec<V, STACK_CAPACITY>,
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> SoaFatMap<K, V, STACK_CAPACITY> {
+    ///Creates a new, empty `SoaFatMap`. Without allocating on the heap.
+    ///This can contain up to `STACK_CAPACITY` pairs without performing any
+    ///heap allocations.
+    pub fn new() -> Self {
+        Self {
+            keys: FatVec::new(),
+            values: FatVec::new(),
+        }
+    }
+
+    ///Creates a new, empty `SoaFatMap` with space to hold at least `capacity` pairs without reallocating
+    ///If `capacity` is less than or equal to `STACK_CAPACITY` the total capacity of this `SoaFatMap` will be equal to `STACK_CAPACITY`.
+    pub fn with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Ok(Self {
+            keys: FatVec::with_capacity(capacity)?,
+            values: FatVec::with_capacity(capacity)?,
+        })
+    }
+
+    ///Creates a new, empty `SoaFatMap` with space to hold at least `capacity` pairs on the heap.
+    ///Upon return, this `SoaFatMap` will be able to hold `STACK_CAPACITY + `capacity` pairs without
+    ///re-allocating.
+    pub fn with_heap_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Ok(Self {
+            keys: FatVec::with_heap_capacity(capacity)?,
+            values: FatVec::with_heap_capacity(capacity)?,
+        })
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> Default for SoaFatMap<K, V, STACK_CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> FallibleLinearMap<K, V>
+    for SoaFatMap<K, V, STACK_CAPACITY>
+{
+    type Backing = (FatVec<K, STACK_CAPACITY>, FatVec<V, STACK_CAPACITY>);
+    type InsertionError = TryReserveError;
+
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError> {
+        match self.index_of(&key) {
+            //SAFETY: `index_of` only returns indices of pairs within the map.
+            Some(index) => Ok(Some(std::mem::replace(
+                unsafe { self.values.get_unchecked_mut(index) },
+                value,
+            ))),
+            None => self.push_unchecked(key, value).map(|_| None),
+        }
+    }
+
+    fn into_inner(self) -> Self::Backing {
+        (self.keys, self.values)
+    }
+
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.index_of(key)?;
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        Some(unsafe { self.remove_index_unchecked(index) })
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> LinearMapView<K, V>
+    for SoaFatMap<K, V, STACK_CAPACITY>
+{
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.keys.iter().zip(self.values.iter())
+    }
+
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> LinearMapValuesMut<K, V>
+    for SoaFatMap<K, V, STACK_CAPACITY>
+{
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> MapIterMut<K, V> for SoaFatMap<K, V, STACK_CAPACITY> {
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.keys.iter().zip(self.values.iter_mut())
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> MapExtract<K, V> for SoaFatMap<K, V, STACK_CAPACITY> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        //SAFETY: there is a value for every key.
+        unsafe { ExtractPairs::new(&mut self.keys, &mut self.values, pred) }
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> MapIndexMut<K, V> for SoaFatMap<K, V, STACK_CAPACITY> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //SAFETY: upheld by caller
+        unsafe {
+            (
+                self.keys.remove_unchecked(index),
+                self.values.remove_unchecked(index),
+            )
+        }
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.keys.push(key)?;
+
+        match self.values.push(value) {
+            Ok(()) => Ok(self.keys.len() - 1),
+            Err(e) => {
+                //take the key back out, so that every key still has a value.
+                self.keys.pop();
+                Err(e)
+            }
+        }
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.keys.reserve(additional)?;
+        self.values.reserve(additional)
+    }
+
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.keys.reserve_total(additional)?;
+        self.values.reserve_total(additional)
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> MapIndex<K, V> for SoaFatMap<K, V, STACK_CAPACITY> {
+    fn index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        //the keys on the heap are only searched once every key on the stack has been.
+        let (stack, heap) = self.keys.as_slices();
+
+        K::position_in_keys(stack, key)
+            .or_else(|| K::position_in_keys(heap, key).map(|i| stack.len() + i))
+    }
+
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        unsafe {
+            (
+                self.keys.get_unchecked(index),
+                self.values.get_unchecked(index),
+            )
+        }
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        unsafe {
+            (
+                self.keys.get_unchecked(index),
+                self.values.get_unchecked_mut(index),
+            )
+        }
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> Reorder for SoaFatMap<K, V, STACK_CAPACITY> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        //SAFETY: upheld by caller
+        unsafe {
+            Reorder::swap_unchecked(&mut self.keys, a, b);
+            Reorder::swap_unchecked(&mut self.values, a, b);
+        }
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //SAFETY: upheld by caller
+        unsafe {
+            self.keys.move_to_front_unchecked(index);
+            self.values.move_to_front_unchecked(index);
+        }
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> PositionalLinearMap<K, V>
+    for SoaFatMap<K, V, STACK_CAPACITY>
+{
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> TryFromIterator<(K, V)>
+    for SoaFatMap<K, V, STACK_CAPACITY>
+{
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+//`try_clone_from` is left to its default, so that a panicking `clone` can never leave the columns at different lengths.
+impl<K: Eq + Clone, V: Clone, const STACK_CAPACITY: usize> TryClone
+    for SoaFatMap<K, V, STACK_CAPACITY>
+{
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(Self {
+            keys: self.keys.try_clone()?,
+            values: self.values.try_clone()?,
+        })
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> IntoIterator for SoaFatMap<K, V, STACK_CAPACITY> {
+    type Item = (K, V);
+
+    type IntoIter = std::iter::Zip<
+        crate::FatVecIterator<K, STACK_CAPACITY>,
+        crate::FatVecIterator<V, STACK_CAPACITY>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter().zip(self.values)
+    }
+}
+
+crate::std_traits::map_traits!([K: Eq, V, const STACK_CAPACITY: usize] SoaFatMap<K, V, STACK_CAPACITY>);
+crate::std_traits::map_index!([K: Eq, V, const STACK_CAPACITY: usize] SoaFatMap<K, V, STACK_CAPACITY>);
+
+#[cfg(feature = "serde")]
+impl<K: Eq + serde::Serialize, V: PartialEq + serde::Serialize, const STACK_CAPACITY: usize>
+    serde::Serialize for SoaFatMap<K, V, STACK_CAPACITY>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serde::serialize_fallible_map(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<
+        'de,
+        K: Eq + serde::Deserialize<'de>,
+        V: serde::Deserialize<'de>,
+        const STACK_CAPACITY: usize,
+    > serde::Deserialize<'de> for SoaFatMap<K, V, STACK_CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
@@ -0,0 +1,322 @@
+use std::{borrow::Borrow, collections::TryReserveError};
+
+use super::FatVec;
+use crate::{
+    sorted::search, Entry, FallibleLinearMap, LinearMapValuesMut, LinearMapView, MapExtract,
+    MapIndex, MapIndexMut, MapIterMut, OccupiedEntry, SortedLinearMap, TryClone, TryExtendError,
+    TryFromIterator, VacantEntry,
+};
+
+///A map backed by a `FatVec` which keeps its pairs sorted by key, so that lookups are O(log n) binary searches.
+///The first `STACK_CAPACITY` pairs are held on the stack, and the rest on the heap.
+pub struct SortedFatMap<K: Ord, V, const STACK_CAPACITY: usize> {
+    fatvec: FatVec<(K, V), STACK_CAPACITY>,
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> SortedFatMap<K, V, STACK_CAPACITY> {
+    ///Creates a new, empty `SortedFatMap`. Without allocating on the heap.
+    pub fn new() -> Self {
+        Self {
+            fatvec: FatVec::new(),
+        }
+    }
+
+    ///Creates a new, empty `SortedFatMap` with space to hold at least `capacity` elements without reallocating
+    ///If `capacity` is less than or equal to `STACK_CAPACITY` the total capacity of this `SortedFatMap` will be equal to `STACK_CAPACITY`.
+    pub fn with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        FatVec::with_capacity(capacity).map(|fatvec| SortedFatMap { fatvec })
+    }
+
+    ///Gets a reference to the value associated with the key, found by binary search.
+    ///Will return None if that key is not in the map.
+    pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = search(self, key).ok()?;
+        //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+        Some(unsafe { self.get_index_unchecked(index) }.1)
+    }
+
+    ///Gets a mutable reference to the value associated with the key, found by binary search.
+    ///Will return None if that key is not in the map.
+    pub fn get_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = search(self, key).ok()?;
+        //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+        Some(unsafe { self.get_index_unchecked_mut(index) }.1)
+    }
+
+    ///Returns true if this map contains the given key, found by binary search. False otherwise.
+    pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        search(self, key).is_ok()
+    }
+
+    ///Tries to remove the entry associated with the given key, found by binary search, returning None if it is not found.
+    pub fn remove_entry<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let index = search(self, key).ok()?;
+        //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+        Some(unsafe { self.fatvec.remove_unchecked(index) })
+    }
+
+    ///Tries to remove the value associated with the given key, found by binary search, returning None if it is not found.
+    pub fn remove<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    ///Gets the given key's corresponding entry in the map for in-place manipulation, found by binary search.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, Self> {
+        match search(self, &key) {
+            //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+            Ok(index) => Entry::Occupied(unsafe { OccupiedEntry::new(self, index) }),
+            Err(_) => Entry::Vacant(VacantEntry::new(self, key)),
+        }
+    }
+
+    ///Inserts the pair at `index`, which must be where `key` belongs in the sorted order.
+    fn insert_at(&mut self, index: usize, key: K, value: V) -> Result<(), TryReserveError> {
+        //SAFETY: indices found by `search` are at most the length of the map.
+        unsafe { self.fatvec.insert_unchecked(index, (key, value)) }
+    }
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> Default for SortedFatMap<K, V, STACK_CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> FallibleLinearMap<K, V>
+    for SortedFatMap<K, V, STACK_CAPACITY>
+{
+    type Backing = FatVec<(K, V), STACK_CAPACITY>;
+    type InsertionError = TryReserveError;
+
+    fn into_inner(self) -> Self::Backing {
+        self.fatvec
+    }
+
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let idx = self.fatvec.iter().position(|(k, _)| k.borrow() == key)?;
+
+        self.fatvec.remove(idx)
+    }
+
+    ///Gets the given key's corresponding entry in the map, found by binary search like the inherent `entry`.
+    fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V, Self>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        SortedFatMap::entry(self, key)
+    }
+
+    ///Inserts the provided pair where its key belongs in the sorted order. If the provided key is
+    ///found it will update the value and return the old value. If not, this may allocate for a new key value pair.
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError> {
+        match search(self, &key) {
+            //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+            Ok(index) => Ok(Some(std::mem::replace(
+                unsafe { self.get_index_unchecked_mut(index) }.1,
+                value,
+            ))),
+            Err(index) => self.insert_at(index, key, value).map(|_| None),
+        }
+    }
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> LinearMapView<K, V>
+    for SortedFatMap<K, V, STACK_CAPACITY>
+{
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.fatvec.iter().map(|(k, v)| (k, v))
+    }
+
+    fn len(&self) -> usize {
+        self.fatvec.len()
+    }
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> LinearMapValuesMut<K, V>
+    for SortedFatMap<K, V, STACK_CAPACITY>
+{
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> SortedLinearMap<K, V>
+    for SortedFatMap<K, V, STACK_CAPACITY>
+{
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> MapIterMut<K, V>
+    for SortedFatMap<K, V, STACK_CAPACITY>
+{
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.fatvec.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> MapExtract<K, V>
+    for SortedFatMap<K, V, STACK_CAPACITY>
+{
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.fatvec.extract_if(move |(k, v)| pred(k, v))
+    }
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> MapIndexMut<K, V>
+    for SortedFatMap<K, V, STACK_CAPACITY>
+{
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //SAFETY: upheld by caller
+        unsafe { self.fatvec.remove_unchecked(index) }
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        let index = search(self, &key).unwrap_or_else(|index| index);
+        self.insert_at(index, key, value)?;
+        Ok(index)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.fatvec.reserve(additional)
+    }
+
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.fatvec.reserve_total(additional)
+    }
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> MapIndex<K, V> for SortedFatMap<K, V, STACK_CAPACITY> {
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.fatvec.get_unchecked(index) };
+        (k, v)
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.fatvec.get_unchecked_mut(index) };
+        (k, v)
+    }
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> TryFromIterator<(K, V)>
+    for SortedFatMap<K, V, STACK_CAPACITY>
+{
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Ord + Clone, V: Clone, const STACK_CAPACITY: usize> TryClone
+    for SortedFatMap<K, V, STACK_CAPACITY>
+{
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.fatvec
+            .try_clone()
+            .map(|fatvec| SortedFatMap { fatvec })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.fatvec.try_clone_from(&source.fatvec)
+    }
+}
+
+impl<K: Ord, V, const STACK_CAPACITY: usize> IntoIterator for SortedFatMap<K, V, STACK_CAPACITY> {
+    type Item = (K, V);
+
+    type IntoIter = crate::FatVecIterator<(K, V), STACK_CAPACITY>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fatvec.into_iter()
+    }
+}
+
+crate::std_traits::map_traits!([K: Ord, V, const STACK_CAPACITY: usize] SortedFatMap<K, V, STACK_CAPACITY>);
+
+impl<K: Ord, V, const STACK_CAPACITY: usize, Q: ?Sized + Ord> std::ops::Index<&Q>
+    for SortedFatMap<K, V, STACK_CAPACITY>
+where
+    K: Borrow<Q>,
+{
+    type Output = V;
+
+    ///Returns a reference to the value corresponding to `key`, found by binary search like `get`.
+    ///
+    ///# Panics
+    ///Panics if `key` is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in map")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K: Ord + serde::Serialize, V: PartialEq + serde::Serialize, const STACK_CAPACITY: usize>
+    serde::Serialize for SortedFatMap<K, V, STACK_CAPACITY>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serde::serialize_fallible_map(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<
+        'de,
+        K: Ord + serde::Deserialize<'de>,
+        V: serde::Deserialize<'de>,
+        const STACK_CAPACITY: usize,
+    > serde::Deserialize<'de> for SortedFatMap<K, V, STACK_CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
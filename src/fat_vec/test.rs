@@ -330,6 +330,30 @@ pub fn remove_unchecked_shifts_from_heap() {
     assert_eq!(list.vec, vec![four, five]);
 }
 
+#[test]
+///remove_unchecked at index STACK_CAPACITY should remove the first element on the heap, leaving the stack untouched.
+pub fn remove_unchecked_at_stack_boundary() {
+    let one = "one";
+    let two = "two";
+    let three = "three";
+    let four = "four";
+
+    let mut list = FatVec::with_array([one, two]);
+
+    list.push(three).unwrap();
+    list.push(four).unwrap();
+
+    //remove_unchecked the start of the heap
+
+    assert_eq!(unsafe { list.remove_unchecked(2) }, "three");
+
+    assert_eq!(
+        unsafe { transmute_unchecked::<RawStackList<&str, 2>, [&str; 2]>(list.stack_list) },
+        [one, two]
+    );
+    assert_eq!(list.vec, vec![four]);
+}
+
 #[test]
 pub fn into_iter_next() {
     let one = "one";
@@ -9,76 +9,181 @@
 #![feature(slice_concat_ext)]
 #![feature(slice_concat_trait)]
 #![feature(generic_const_exprs)]
+#![feature(min_specialization)]
+#![feature(portable_simd)]
+#![feature(const_trait_impl)]
+#![feature(impl_trait_in_assoc_type)]
 pub mod array;
+mod bimap;
+mod drain;
+mod entry;
+mod extend;
 ///This is in the crate root because it's used internally but we still need it throughout the
 ///fallible module internally.
 pub mod stack_list;
 mod fat_vec;
+mod multimap;
+mod policy;
+mod positional;
+mod search;
+mod soa;
+mod sorted;
+mod std_traits;
+mod try_clone;
 mod vec;
 mod vecdeque;
-
-use std::error::Error;
-
-pub use fat_vec::{map::*, set::*, FatVec, FatVecIterator};
-pub use stack_list::{map::*, set::*};
-pub use vec::{map::*, set::*, Vec};
-pub use vecdeque::{map::*, set::*};
-
-
-
+mod window;
+
+use std::{borrow::Borrow, error::Error};
+
+pub use bimap::{FallibleLinearBiMap, Overwritten};
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use extend::{TryExtendError, TryFromIterator};
+pub use fat_vec::{
+    map::*, multimap::*, set::*, soa_map::*, sorted_map::*, FatVec, FatVecIterator,
+};
+pub use multimap::FallibleLinearMultiMap;
+pub use policy::{AccessPolicy, MoveToFront, Transpose};
+pub use positional::PositionalLinearMap;
+pub use sorted::SortedLinearMap;
+pub use stack_list::{bimap::*, lru::*, map::*, set::*, soa_map::*, sorted_map::*};
+pub use try_clone::TryClone;
+pub use vec::{
+    adaptive_map::*, bimap::*, fingerprint_map::*, map::*, multimap::*, self_organizing_map::*, set::*, soa_map::*,
+    sorted_map::*, Vec,
+};
+pub use vecdeque::{lru::*, map::*, self_organizing_map::*, set::*};
+pub use window::InfallibleMapWindow;
+
+
+
+#[cfg(feature = "panicking")]
+pub mod panicking;
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
 #[cfg(test)]
 mod test;
 
 ///Sealed trait to provide mutable iteration without allowing consumers
 ///to violate the invariants of the map types
 pub(crate) trait MapIterMut<K, V> {
-    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut (K, V)>
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
     where
         K: 'a,
         V: 'a;
 }
 
-//sealed trait
-#[allow(private_bounds)]
-//Never implement clone: panics on alloc failure.
-///Provides methods for maps backed by linear data structures like arrays and vectors.
-///Because arrays may implement this type, we cannot assume that implementors will be dynamically sized.
-///Only methods which do not require manipulating the length or capacity of the store are provided here:
-///this is to permit the implementation of fixed sized types backed by arrays.
-pub trait FallibleLinearMap<K: Eq, V>: MapIterMut<K, V> {
-    type Backing;
-    //Aliasing the InsertionError allows us to implement this for both heap allocated types which return TryReserveError
-    //and the stack allocated ArrayVec which return ArrayVecError.
-    type InsertionError: Error;
+///Sealed trait for removing pairs in bulk, which only maps able to change their length can implement.
+pub(crate) trait MapExtract<K, V> {
+    ///Removes and yields every pair for which `pred` returns true, compacting the kept pairs in a single pass
+    ///over the backing store. Pairs which have not been visited when the iterator is dropped are kept.
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a;
+}
 
-    ///Inserts a key-value pair into the map.
-    ///If the map did not have this key present, None is returned.
-    ///If the map did have this key present, the value is updated, and the old value is returned. The key is not updated, though; this matters for types that can be == without being identical. See the module-level documentation for more.
-    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError>;
-    ///Consumes self, returning the underlying store.
-    fn into_inner(self) -> Self::Backing;
+///Sealed trait providing positional access to the store backing a map.
+///This lets operations which have already located a key, like the entry api, act on
+///it without scanning the map a second time.
+pub(crate) trait MapIndex<K, V> {
+    ///SAFETY: UB if `index` is >= the length of the map.
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V);
+
+    ///SAFETY: UB if `index` is >= the length of the map.
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V);
+
+    ///Returns the index of the pair whose key is equal to `key`, or None if it is not in the map.
+    ///Maps whose pairs or keys can be viewed as slices override this to search them with `KeySearch`, which compares
+    ///primitive keys with SIMD.
+    fn index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Eq + Borrow<Q>,
+        Self: LinearMapView<K, V>,
+    {
+        self.iter().position(|(k, _)| k.borrow() == key)
+    }
+}
+
+///Sealed trait for adding and removing pairs by position, which only maps able to change their length can implement.
+pub(crate) trait MapIndexMut<K, V> {
+    ///SAFETY: UB if `index` is >= the length of the map.
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V);
+
+    ///Adds the pair to the backing store *without* checking if `key` is already present, returning the index it was placed at.
+    ///Callers must guarantee that it isn't, else the pair will never be reachable.
+    ///Most maps append the pair, but sorted maps place it according to its key.
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError>
+    where
+        K: Eq,
+        Self: FallibleLinearMap<K, V>;
 
-    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (K, V)>
+    ///Ensures there is room for at least `additional` more pairs without reallocating.
+    ///Maps which cannot grow return an error if they do not already have that much room.
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError>
+    where
+        K: Eq,
+        Self: FallibleLinearMap<K, V>;
+
+    ///Reserves room for `additional` more pairs before inserting many at once.
+    ///Unlike `reserve`, maps which cannot grow do nothing: each insertion reports when they are full.
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError>
+    where
+        K: Eq,
+        Self: FallibleLinearMap<K, V>;
+
+    ///Adds the pair if `key` is not already in the map, returning true if it did. The pair is dropped otherwise.
+    ///Goes through `entry` by default: maps whose pairs are slow to reach by index override this to find the key directly.
+    fn insert_if_vacant(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<bool, <Self as FallibleLinearMap<K, V>>::InsertionError>
+    where
+        K: Eq,
+        Self: FallibleLinearMap<K, V> + Sized,
+    {
+        match self.entry(key) {
+            Entry::Occupied(_) => Ok(false),
+            Entry::Vacant(vacant) => vacant.try_insert(value).map(|_| true),
+        }
+    }
+}
+
+//sealed trait
+#[allow(private_bounds)]
+///Provides read-only access to maps backed by linear data structures: lookups and iteration.
+///Every map in this crate implements it, including the fixed size `ArrayMap`, so generic code which only reads a map
+///should be bounded on this trait rather than on `FallibleLinearMap`.
+pub trait LinearMapView<K: Eq, V>: MapIndex<K, V> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
     where
         K: 'a,
         V: 'a;
 
     fn len(&self) -> usize;
 
-    fn remove_entry(&mut self, key: &K) -> Option<(K, V)>;
-
-    //notice to implementors: if calling as_slice is not zero cost, override
-    //this default implementation with one that is.
+    //notice to implementors: override `MapIndex::index_of` rather than this, so that every lookup benefits.
     ///Returns true if this map contains the given key. False otherwise.
-    fn contains_key(&self, key: &K) -> bool {
-        for (k, _) in self.iter() {
-            if k == key {
-                return true;
-            }
-        }
-        false
+    ///The key may be any borrowed form of the map's key type.
+    fn contains_key<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.index_of(key).is_some()
     }
 
     ///Returns true if this map contains a given value. False otherwise.
@@ -96,35 +201,201 @@ pub trait FallibleLinearMap<K: Eq, V>: MapIterMut<K, V> {
 
     ///Gets a reference with the associated key. Will return None if that i
     ///key is not in the map.
-    fn get<'a, 'k>(&'a self, key: &'k K) -> Option<&'a V>
+    ///The key may be any borrowed form of the map's key type.
+    fn get<'a, Q: ?Sized + Eq>(&'a self, key: &Q) -> Option<&'a V>
     //this bound confuses the hell out of me.
+    where
+        K: 'a + Borrow<Q>,
+    {
+        let index = self.index_of(key)?;
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        Some(unsafe { self.get_index_unchecked(index) }.1)
+    }
+
+    ///Returns `true` if this map is empty and `false` otherwise.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///Iterator over the keys of this map.
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
     where
         K: 'a,
+        V: 'a,
     {
-        self.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+        self.iter().map(|(k, _)| k)
+    }
+
+    ///Gets a reference to the nth value in the map.
+    ///Will return None if index is out of bounds.
+    fn nth_value<'a>(&'a self, index: usize) -> Option<&'a V>
+    where
+        K: 'a,
+    {
+        match index < self.len() {
+            //SAFETY: index < len.
+            true => Some(unsafe { self.get_index_unchecked(index) }.1),
+            false => None,
+        }
+    }
+
+    ///Gets a reference to the nth value in the map.
+    ///Will return None if index is out of bounds.
+    fn nth_key<'a>(&'a self, index: usize) -> Option<&'a K>
+    where
+        V: 'a,
+    {
+        match index < self.len() {
+            //SAFETY: index < len.
+            true => Some(unsafe { self.get_index_unchecked(index) }.0),
+            false => None,
+        }
+    }
+
+    ///Iterator over the values of this map, returning a shared reference to each.
+    fn values<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.iter().map(|(_, v)| v)
+    }
+
+    ///Returns `true` if `self` and `other` hold equal pairs in the same order.
+    ///`==` ignores the order of the pairs, so two maps with the same pairs inserted in a different order are equal.
+    fn eq_ordered<M: LinearMapView<K, V> + ?Sized>(&self, other: &M) -> bool
+    where
+        V: PartialEq,
+    {
+        self.len() == other.len() && self.iter().eq(other.iter())
     }
+}
 
+//sealed trait
+#[allow(private_bounds)]
+///Provides methods which mutate the values of a map in place, without adding or removing any pairs.
+///Like `LinearMapView`, this is implemented by every map, including `ArrayMap`.
+pub trait LinearMapValuesMut<K: Eq, V>: LinearMapView<K, V> + MapIterMut<K, V> {
     ///Gets a mutable reference with the associated key. Will return None if that
     ///key is not in the map.
-    fn get_mut<'a, 'k>(&'a mut self, key: &'k K) -> Option<&'a mut V>
+    ///The key may be any borrowed form of the map's key type.
+    fn get_mut<'a, Q: ?Sized + Eq>(&'a mut self, key: &Q) -> Option<&'a mut V>
+    where
+        K: 'a + Borrow<Q>,
+    {
+        let index = self.index_of(key)?;
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        Some(unsafe { self.get_index_unchecked_mut(index) }.1)
+    }
+
+    ///Gets a reference to the nth value in the map.
+    ///Will return None if index is out of bounds.    
+    fn nth_value_mut<'a>(&'a mut self, index: usize) -> Option<&'a mut V>
     where
         K: 'a,
     {
-        self.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v)
+        match index < self.len() {
+            //SAFETY: index < len.
+            true => Some(unsafe { self.get_index_unchecked_mut(index) }.1),
+            false => None,
+        }
     }
 
-    ///Returns `true` if this map is empty and `false` otherwise.
-    fn is_empty(&self) -> bool {
-        self.len() == 0
+    ///Iterator over the values of this map, returning an exclusive reference to each.
+    fn values_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.iter_mut().map(|(_, v)| v)
     }
 
-    ///Iterator over the keys of this map.
-    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    ///Searches for a key == key in the map. If it is present
+    ///replaces its value with "value". If not, it does nothing.
+    ///The key may be any borrowed form of the map's key type.
+    fn replace<Q: ?Sized + Eq>(&mut self, key: &Q, value: V)
+    where
+        K: Borrow<Q>,
+    {
+        if let Some(index) = self.index_of(key) {
+            //SAFETY: `index_of` only returns indices of pairs within the map.
+            *unsafe { self.get_index_unchecked_mut(index) }.1 = value;
+        }
+    }
+}
+
+//sealed trait
+#[allow(private_bounds)]
+//Never implement clone: panics on alloc failure. Implement `TryClone` instead.
+///Provides methods for maps backed by linear data structures which can change their length, like vectors.
+///Lookups and iteration are provided by `LinearMapView`, and mutation of the values by `LinearMapValuesMut`, which
+///fixed size maps like `ArrayMap` implement as well. This trait adds the methods which insert or remove pairs.
+pub trait FallibleLinearMap<K: Eq, V>:
+    LinearMapValuesMut<K, V> + MapExtract<K, V> + MapIndexMut<K, V>
+{
+    type Backing;
+    //Aliasing the InsertionError allows us to implement this for both heap allocated types which return TryReserveError
+    //and the stack allocated ArrayVec which return ArrayVecError.
+    type InsertionError: Error;
+
+    ///Inserts a key-value pair into the map.
+    ///If the map did not have this key present, None is returned.
+    ///If the map did have this key present, the value is updated, and the old value is returned. The key is not updated, though; this matters for types that can be == without being identical. See the module-level documentation for more.
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError>;
+
+    ///Like `insert`, but takes a borrowed form of the key and only converts it into an owned `K`
+    ///if the key is not already present in the map. This saves an allocation on every update of a `String` keyed map, for example.
+    ///
+    ///Note that the conversion itself is performed by `ToOwned`, which may panic if it fails to allocate.
+    fn insert_ref<Q>(&mut self, key: &Q, value: V) -> Result<Option<V>, Self::InsertionError>
     where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + ToOwned<Owned = K>,
+    {
+        match self.index_of(key) {
+            Some(index) => {
+                //SAFETY: `index_of` only returns indices of pairs within the map.
+                let (_, v) = unsafe { self.get_index_unchecked_mut(index) };
+                Ok(Some(std::mem::replace(v, value)))
+            }
+            None => self.push_unchecked(key.to_owned(), value).map(|_| None),
+        }
+    }
+    ///Consumes self, returning the underlying store.
+    fn into_inner(self) -> Self::Backing;
+
+    ///Tries to remove the entry associated with the given key, returning None if it is not found.
+    ///The key may be any borrowed form of the map's key type.
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>;
+
+    ///Gets the given key's corresponding entry in the map for in-place manipulation.
+    ///The map is scanned for the key once, here: no method on the returned `Entry` scans it again.
+    fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V, Self>
+    where
+        Self: Sized,
         K: 'a,
         V: 'a,
     {
-        self.iter().map(|(k, _)| k)
+        match self.index_of(&key) {
+            //SAFETY: `index_of` only returns indices of pairs within the map.
+            Some(index) => Entry::Occupied(unsafe { OccupiedEntry::new(self, index) }),
+            None => Entry::Vacant(VacantEntry::new(self, key)),
+        }
+    }
+
+    ///Reserves room for `SLOTS` new keys up front, returning a window into the map through which up to `SLOTS` new keys can be inserted
+    ///without the possibility of failure. Returns an error if the room could not be reserved.
+    fn try_reserve_window<'a, const SLOTS: usize>(
+        &'a mut self,
+    ) -> Result<InfallibleMapWindow<'a, K, V, Self, SLOTS>, Self::InsertionError>
+    where
+        Self: Sized,
+    {
+        self.reserve(SLOTS)?;
+
+        Ok(InfallibleMapWindow::new(self))
     }
 
     /*
@@ -144,66 +415,66 @@ pub trait FallibleLinearMap<K: Eq, V>: MapIterMut<K, V> {
     {
         iter.for_each(|(k, v)| self.replace(&k, v.clone().to_owned()))
     }*/
-    ///Gets a reference to the nth value in the map.
-    ///Will return None if index is out of bounds.
-    fn nth_value<'a>(&'a self, index: usize) -> Option<&'a V>
-    where
-        K: 'a,
-    {
-        self.iter().nth(index).map(|(_, v)| v)
-    }
 
-    ///Gets a reference to the nth value in the map.
-    ///Will return None if index is out of bounds.    
-    fn nth_value_mut<'a>(&'a mut self, index: usize) -> Option<&'a mut V>
+    ///Tries to remove the value associated with the given key, returning None if it is not found.
+    ///The key may be any borrowed form of the map's key type.
+    fn remove<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<V>
     where
-        K: 'a,
+        K: Borrow<Q>,
     {
-        self.iter_mut().nth(index).map(|(_, v)| v)
+        self.remove_entry(key).map(|(_, v)| v)
     }
 
-    ///Gets a reference to the nth value in the map.
-    ///Will return None if index is out of bounds.
-    fn nth_key<'a>(&'a self, index: usize) -> Option<&'a K>
-    where
-        V: 'a,
-    {
-        self.iter().nth(index).map(|(k, _)| k)
+    ///Inserts every pair of `iter` into the map. As with `insert`, a pair whose key is already present updates the value.
+    ///Room is reserved once from the iterator's `size_hint` if the map can grow.
+    ///
+    ///On failure, the pairs inserted before the error remain in the map.
+    fn try_extend<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryExtendError<Self::InsertionError>> {
+        let iter = iter.into_iter();
+
+        self.reserve_hint(iter.size_hint().0)
+            .map_err(|e| TryExtendError::new(e, 0))?;
+
+        extend::try_extend_with(iter, |(k, v)| self.insert(k, v).map(drop))
     }
 
-    ///Tries to remove the value associated with the given key, returning None if it is not found.
-    fn remove(&mut self, key: &K) -> Option<V> {
-        self.remove_entry(key).map(|(_, v)| v)
+    ///Retains only the pairs for which `f` returns true, removing the rest.
+    ///The kept pairs are compacted in a single pass and keep their order.
+    fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.extract_if(|k, v| !f(k, v)).for_each(drop);
     }
 
-    ///Iterator over the values of this map, returning a shared reference to each.
-    fn values<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    ///Returns an iterator which removes and yields every pair for which `pred` returns true.
+    ///The kept pairs are compacted in a single pass and keep their order.
+    ///
+    ///If the iterator is dropped before it is exhausted, the pairs it has not visited are kept.
+    fn extract_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
     where
         K: 'a,
         V: 'a,
     {
-        self.iter().map(|(_, v)| v)
+        self.extract_pairs_if(pred)
     }
 
-    ///Iterator over the values of this map, returning an exclusive reference to each.
-    fn values_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
+    ///Removes every pair from the map, returning them in an iterator.
+    ///
+    ///The map is left empty even if the iterator is dropped before it is exhausted.
+    fn drain<'a>(&'a mut self) -> impl Iterator<Item = (K, V)> + 'a
     where
         K: 'a,
         V: 'a,
     {
-        self.iter_mut().map(|(_, v)| v)
-    }
-
-    ///Searches for a key == key in the map. If it is present
-    ///replaces its value with "value". If not, it does nothing.
-    fn replace(&mut self, key: &K, value: V) {
-        self.iter_mut()
-            .find(|(k, _)| k == key)
-            .map(|(_, v)| *v = value);
+        drain::Drain(self.extract_pairs_if(|_, _| true))
     }
 }
 
-//Never implement clone: panics on alloc failure.
+//Never implement clone: panics on alloc failure. Implement `TryClone` instead.
 ///Set types backed by a FallibleLinearMap<T, ()>.
 pub trait FallibleLinearSet<T: Eq> {
     ///The map type which backs this set.
@@ -217,7 +488,11 @@ pub trait FallibleLinearSet<T: Eq> {
     fn map_mut(&mut self) -> &mut Self::Backing;
 
     ///Returns true if the referenced value is in the set, false otherwise.
-    fn contains(&self, value: &T) -> bool {
+    ///The value may be any borrowed form of the set's value type.
+    fn contains<Q: ?Sized + Eq>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
         self.map().contains_key(value)
     }
 
@@ -252,18 +527,149 @@ pub trait FallibleLinearSet<T: Eq> {
     }
 
     ///Attempts to remove the referenced value from the set, returning None if it is not present.
-    fn remove(&mut self, value: &T) -> Option<T> {
+    ///The value may be any borrowed form of the set's value type.
+    fn remove<Q: ?Sized + Eq>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
         self.map_mut().remove_entry(value).map(|(k, _)| k)
     }
-}
 
-pub struct InfallibleMapWindow<
-    'backing,
-    K: Eq,
-    V,
-    Backing: FallibleLinearMap<K, V>,
-    const SLOTS: usize,
-> {
-    backing: &'backing mut Backing,
-    phantom: (K, V),
+    ///Inserts every value of `iter` into the set. As with `insert`, values which are already present are not replaced.
+    ///Room is reserved once from the iterator's `size_hint` if the set can grow.
+    ///
+    ///On failure, the values inserted before the error remain in the set.
+    fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryExtendError<<Self::Backing as FallibleLinearMap<T, ()>>::InsertionError>>
+    {
+        self.map_mut().try_extend(iter.into_iter().map(|t| (t, ())))
+    }
+
+    ///Retains only the values for which `f` returns true, removing the rest.
+    ///The kept values are compacted in a single pass and keep their order.
+    fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.map_mut().retain(|t, _| f(t));
+    }
+
+    ///Returns an iterator which removes and yields every value for which `pred` returns true.
+    ///The kept values are compacted in a single pass and keep their order.
+    ///
+    ///If the iterator is dropped before it is exhausted, the values it has not visited are kept.
+    fn extract_if<'a, F: FnMut(&T) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        self.map_mut()
+            .extract_if(move |t, _| pred(t))
+            .map(|(t, _)| t)
+    }
+
+    ///Removes every value from the set, returning them in an iterator.
+    ///
+    ///The set is left empty even if the iterator is dropped before it is exhausted.
+    fn drain<'a>(&'a mut self) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        self.map_mut().drain().map(|(t, _)| t)
+    }
+
+    ///Iterates over the values in `self` or `other`, without duplicates.
+    ///Values from `self` come first, in order, followed by those only present in `other`.
+    fn union<'a, S: FallibleLinearSet<T> + ?Sized>(
+        &'a self,
+        other: &'a S,
+    ) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.values().chain(other.difference(self))
+    }
+
+    ///Iterates over the values in both `self` and `other`, in the order they appear in `self`.
+    fn intersection<'a, S: FallibleLinearSet<T> + ?Sized>(
+        &'a self,
+        other: &'a S,
+    ) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.values().filter(move |v| other.contains(*v))
+    }
+
+    ///Iterates over the values in `self` which are not in `other`.
+    fn difference<'a, S: FallibleLinearSet<T> + ?Sized>(
+        &'a self,
+        other: &'a S,
+    ) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.values().filter(move |v| !other.contains(*v))
+    }
+
+    ///Iterates over the values in `self` or `other` but not in both.
+    ///Values only present in `self` come first, followed by those only present in `other`.
+    fn symmetric_difference<'a, S: FallibleLinearSet<T> + ?Sized>(
+        &'a self,
+        other: &'a S,
+    ) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.difference(other).chain(other.difference(self))
+    }
+
+    ///Returns `true` if every value in `self` is also in `other`.
+    fn is_subset<S: FallibleLinearSet<T> + ?Sized>(&self, other: &S) -> bool {
+        self.len() <= other.len() && self.values().all(|v| other.contains(v))
+    }
+
+    ///Returns `true` if every value in `other` is also in `self`.
+    fn is_superset<S: FallibleLinearSet<T> + ?Sized>(&self, other: &S) -> bool {
+        other.is_subset(self)
+    }
+
+    ///Returns `true` if `self` and `other` have no values in common.
+    fn is_disjoint<S: FallibleLinearSet<T> + ?Sized>(&self, other: &S) -> bool {
+        self.intersection(other).next().is_none()
+    }
+
+    ///Returns `true` if `self` and `other` hold equal values in the same order.
+    ///`==` ignores the order of the values, so two sets with the same values inserted in a different order are equal.
+    fn eq_ordered<S: FallibleLinearSet<T> + ?Sized>(&self, other: &S) -> bool {
+        self.len() == other.len() && self.values().eq(other.values())
+    }
+
+    ///Inserts a clone of every value in `other` which is not already in `self`.
+    ///Room for all the new values is reserved before any are inserted, so if this returns an error `self` is left unchanged.
+    fn try_union_with<S: FallibleLinearSet<T> + ?Sized>(
+        &mut self,
+        other: &S,
+    ) -> Result<(), <Self::Backing as FallibleLinearMap<T, ()>>::InsertionError>
+    where
+        T: Clone,
+    {
+        let missing = other.difference(self).count();
+        self.map_mut().reserve(missing)?;
+
+        for value in other.values() {
+            if !self.contains(value) {
+                //we just checked that the value is not present, and values in `other` are unique.
+                self.map_mut().push_unchecked(value.clone(), ())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///Removes every value from `self` which is not also in `other`.
+    fn retain_intersection<S: FallibleLinearSet<T> + ?Sized>(&mut self, other: &S) {
+        self.retain(|v| other.contains(v));
+    }
 }
@@ -0,0 +1,106 @@
+use std::{borrow::Borrow, error::Error};
+
+use crate::{
+    drain::Drain,
+    extend::{try_extend_with, TryExtendError},
+    MapExtract, MapIterMut,
+};
+
+//sealed trait
+#[allow(private_bounds)]
+///Provides methods for multimaps backed by linear data structures: maps which may hold any number of values for the same key.
+///Pairs are kept in insertion order, so the values of a key are always yielded in the order they were inserted.
+pub trait FallibleLinearMultiMap<K: Eq, V>: MapIterMut<K, V> + MapExtract<K, V> {
+    type Backing;
+    type InsertionError: Error;
+
+    ///Appends the pair to the map, whether or not its key is already present.
+    fn insert(&mut self, key: K, value: V) -> Result<(), Self::InsertionError>;
+
+    ///Consumes self, returning the underlying store.
+    fn into_inner(self) -> Self::Backing;
+
+    ///Iterates over every pair in the map, in insertion order.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (K, V)>
+    where
+        K: 'a,
+        V: 'a;
+
+    ///Returns the number of pairs in the map, counting every value of a repeated key.
+    fn len(&self) -> usize;
+
+    ///Returns true if the map contains no pairs.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///Returns true if this map contains at least one value for the given key. False otherwise.
+    fn contains_key<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.iter().any(|(k, _)| k.borrow() == key)
+    }
+
+    ///Iterates over every value associated with the key, in insertion order.
+    fn get_all<'a, Q: ?Sized + Eq>(&'a self, key: &'a Q) -> impl Iterator<Item = &'a V>
+    where
+        K: Borrow<Q> + 'a,
+        V: 'a,
+    {
+        self.iter()
+            .filter(move |(k, _)| k.borrow() == key)
+            .map(|(_, v)| v)
+    }
+
+    ///Mutably iterates over every value associated with the key, in insertion order.
+    fn get_all_mut<'a, Q: ?Sized + Eq>(&'a mut self, key: &'a Q) -> impl Iterator<Item = &'a mut V>
+    where
+        K: Borrow<Q> + 'a,
+        V: 'a,
+    {
+        self.iter_mut()
+            .filter(move |(k, _)| (*k).borrow() == key)
+            .map(|(_, v)| v)
+    }
+
+    ///Gets a reference to the first value inserted for the key.
+    ///Will return None if that key is not in the map.
+    fn get_first<'a, Q: ?Sized + Eq>(&'a self, key: &Q) -> Option<&'a V>
+    where
+        K: 'a + Borrow<Q>,
+    {
+        self.iter().find(|(k, _)| k.borrow() == key).map(|(_, v)| v)
+    }
+
+    ///Gets a reference to the last value inserted for the key.
+    ///Will return None if that key is not in the map.
+    fn get_last<'a, Q: ?Sized + Eq>(&'a self, key: &Q) -> Option<&'a V>
+    where
+        K: 'a + Borrow<Q>,
+    {
+        self.iter()
+            .filter(|(k, _)| k.borrow() == key)
+            .last()
+            .map(|(_, v)| v)
+    }
+
+    ///Removes every value associated with the key, yielding them in insertion order.
+    ///Every value is removed even if the returned iterator is dropped before it is exhausted.
+    fn remove_all<'a, Q: ?Sized + Eq>(&'a mut self, key: &'a Q) -> impl Iterator<Item = V> + 'a
+    where
+        K: Borrow<Q> + 'a,
+        V: 'a,
+    {
+        Drain(self.extract_pairs_if(move |k, _| k.borrow() == key)).map(|(_, v)| v)
+    }
+
+    ///Appends every pair of `iter` to the map.
+    ///On failure, the pairs inserted before the error remain in the map.
+    fn try_extend<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryExtendError<Self::InsertionError>> {
+        try_extend_with(iter.into_iter(), |(k, v)| self.insert(k, v))
+    }
+}
@@ -0,0 +1,498 @@
+use std::{
+    alloc::handle_alloc_error,
+    borrow::Borrow,
+    collections::{TryReserveError, TryReserveErrorKind},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{
+    stack_list::error::PushError, Entry, FallibleLinearMap, FallibleLinearSet, LinearMapValuesMut,
+    LinearMapView,
+};
+
+pub use crate::{
+    array::map::ArrayMap, DequeMap, DequeSet, FatMap, FatSet, StackMap, StackSet, VecMap, VecSet,
+};
+
+///Sealed trait describing how each `InsertionError` is escalated when an infallible method fails.
+pub(crate) trait InsertionFailure {
+    fn fail(self) -> !;
+}
+
+impl InsertionFailure for TryReserveError {
+    ///Mirrors the behavior of the `std` collections: allocator failures go through the global alloc error handler,
+    ///which aborts by default, while overflowing the maximum capacity panics.
+    fn fail(self) -> ! {
+        match self.kind() {
+            TryReserveErrorKind::AllocError { layout, .. } => handle_alloc_error(layout),
+            TryReserveErrorKind::CapacityOverflow => panic!("capacity overflow"),
+        }
+    }
+}
+
+impl InsertionFailure for PushError {
+    fn fail(self) -> ! {
+        panic!("{self}")
+    }
+}
+
+///Provides the methods of `FallibleLinearMap` with std-like signatures, aborting or panicking if an insertion fails.
+///This is implemented for every `FallibleLinearMap`.
+///
+///Because the method names are shared with `FallibleLinearMap`, `LinearMapView` and `LinearMapValuesMut` you should not bring
+///those traits into scope alongside this one, else calls to methods like `insert` or `get` are ambiguous.
+pub trait InfallibleLinearMap<K: Eq, V> {
+    type Backing;
+
+    ///Inserts a key-value pair into the map.
+    ///If the map did not have this key present, None is returned.
+    ///If the map did have this key present, the value is updated, and the old value is returned.
+    ///
+    ///Panics if a `StackMap` is full. Heap backed maps abort on allocation failure, like `std`.
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+    ///Like `insert`, but takes a borrowed form of the key and only converts it into an owned `K`
+    ///if the key is not already present in the map.
+    fn insert_ref<Q>(&mut self, key: &Q, value: V) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + ToOwned<Owned = K>;
+
+    ///Gets the given key's corresponding entry in the map for in-place manipulation.
+    fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V, Self>
+    where
+        Self: Sized + FallibleLinearMap<K, V>,
+        K: 'a,
+        V: 'a;
+
+    ///Consumes self, returning the underlying store.
+    fn into_inner(self) -> Self::Backing;
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a;
+
+    fn len(&self) -> usize;
+
+    ///Returns `true` if this map is empty and `false` otherwise.
+    fn is_empty(&self) -> bool;
+
+    ///Tries to remove the entry associated with the given key, returning None if it is not found.
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>;
+
+    ///Tries to remove the value associated with the given key, returning None if it is not found.
+    fn remove<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>;
+
+    ///Returns true if this map contains the given key. False otherwise.
+    fn contains_key<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>;
+
+    ///Returns true if this map contains a given value. False otherwise.
+    fn contains_value(&self, value: &V) -> bool
+    where
+        V: PartialEq;
+
+    ///Gets a reference with the associated key. Will return None if that
+    ///key is not in the map.
+    fn get<'a, Q: ?Sized + Eq>(&'a self, key: &Q) -> Option<&'a V>
+    where
+        K: 'a + Borrow<Q>;
+
+    ///Gets a mutable reference with the associated key. Will return None if that
+    ///key is not in the map.
+    fn get_mut<'a, Q: ?Sized + Eq>(&'a mut self, key: &Q) -> Option<&'a mut V>
+    where
+        K: 'a + Borrow<Q>;
+
+    ///Iterator over the keys of this map.
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+        V: 'a;
+
+    ///Iterator over the values of this map, returning a shared reference to each.
+    fn values<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        K: 'a,
+        V: 'a;
+
+    ///Iterator over the values of this map, returning an exclusive reference to each.
+    fn values_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
+    where
+        K: 'a,
+        V: 'a;
+
+    ///Gets a reference to the nth value in the map.
+    ///Will return None if index is out of bounds.
+    fn nth_value<'a>(&'a self, index: usize) -> Option<&'a V>
+    where
+        K: 'a;
+
+    ///Gets an exclusive reference to the nth value in the map.
+    ///Will return None if index is out of bounds.
+    fn nth_value_mut<'a>(&'a mut self, index: usize) -> Option<&'a mut V>
+    where
+        K: 'a;
+
+    ///Gets a reference to the nth key in the map.
+    ///Will return None if index is out of bounds.
+    fn nth_key<'a>(&'a self, index: usize) -> Option<&'a K>
+    where
+        V: 'a;
+
+    ///Searches for a key == key in the map. If it is present
+    ///replaces its value with "value". If not, it does nothing.
+    fn replace<Q: ?Sized + Eq>(&mut self, key: &Q, value: V)
+    where
+        K: Borrow<Q>;
+
+    ///Retains only the pairs for which `f` returns true, removing the rest.
+    fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, f: F);
+
+    ///Returns an iterator which removes and yields every pair for which `pred` returns true.
+    ///If the iterator is dropped before it is exhausted, the pairs it has not visited are kept.
+    fn extract_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a;
+
+    ///Removes every pair from the map, returning them in an iterator.
+    fn drain<'a>(&'a mut self) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a;
+}
+
+#[allow(private_bounds)]
+impl<K: Eq, V, M: FallibleLinearMap<K, V>> InfallibleLinearMap<K, V> for M
+where
+    M::InsertionError: InsertionFailure,
+{
+    type Backing = <M as FallibleLinearMap<K, V>>::Backing;
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        FallibleLinearMap::insert(self, key, value).unwrap_or_else(|e| e.fail())
+    }
+
+    fn insert_ref<Q>(&mut self, key: &Q, value: V) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + ToOwned<Owned = K>,
+    {
+        FallibleLinearMap::insert_ref(self, key, value).unwrap_or_else(|e| e.fail())
+    }
+
+    fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V, Self>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        FallibleLinearMap::entry(self, key)
+    }
+
+    fn into_inner(self) -> Self::Backing {
+        FallibleLinearMap::into_inner(self)
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        LinearMapView::iter(self)
+    }
+
+    fn len(&self) -> usize {
+        LinearMapView::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        LinearMapView::is_empty(self)
+    }
+
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        FallibleLinearMap::remove_entry(self, key)
+    }
+
+    fn remove<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        FallibleLinearMap::remove(self, key)
+    }
+
+    fn contains_key<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        LinearMapView::contains_key(self, key)
+    }
+
+    fn contains_value(&self, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        LinearMapView::contains_value(self, value)
+    }
+
+    fn get<'a, Q: ?Sized + Eq>(&'a self, key: &Q) -> Option<&'a V>
+    where
+        K: 'a + Borrow<Q>,
+    {
+        LinearMapView::get(self, key)
+    }
+
+    fn get_mut<'a, Q: ?Sized + Eq>(&'a mut self, key: &Q) -> Option<&'a mut V>
+    where
+        K: 'a + Borrow<Q>,
+    {
+        LinearMapValuesMut::get_mut(self, key)
+    }
+
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        LinearMapView::keys(self)
+    }
+
+    fn values<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        LinearMapView::values(self)
+    }
+
+    fn values_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        LinearMapValuesMut::values_mut(self)
+    }
+
+    fn nth_value<'a>(&'a self, index: usize) -> Option<&'a V>
+    where
+        K: 'a,
+    {
+        LinearMapView::nth_value(self, index)
+    }
+
+    fn nth_value_mut<'a>(&'a mut self, index: usize) -> Option<&'a mut V>
+    where
+        K: 'a,
+    {
+        LinearMapValuesMut::nth_value_mut(self, index)
+    }
+
+    fn nth_key<'a>(&'a self, index: usize) -> Option<&'a K>
+    where
+        V: 'a,
+    {
+        LinearMapView::nth_key(self, index)
+    }
+
+    fn replace<Q: ?Sized + Eq>(&mut self, key: &Q, value: V)
+    where
+        K: Borrow<Q>,
+    {
+        LinearMapValuesMut::replace(self, key, value)
+    }
+
+    fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, f: F) {
+        FallibleLinearMap::retain(self, f)
+    }
+
+    fn extract_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        FallibleLinearMap::extract_if(self, pred)
+    }
+
+    fn drain<'a>(&'a mut self) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        FallibleLinearMap::drain(self)
+    }
+}
+
+///Provides the methods of `FallibleLinearSet` with std-like signatures, aborting or panicking if an insertion fails.
+///This is implemented for every `FallibleLinearSet`.
+///
+///Because the method names are shared with `FallibleLinearSet` you should only bring one of the two into scope at a time,
+///else calls to methods like `insert` are ambiguous.
+pub trait InfallibleLinearSet<T: Eq> {
+    ///Adds a value to the set.
+    ///If the set did not previously contain this value, true is returned.
+    ///If the set already contained this value, false is returned, and the set is not modified.
+    ///
+    ///Panics if a `StackSet` is full. Heap backed sets abort on allocation failure, like `std`.
+    fn insert(&mut self, value: T) -> bool;
+
+    ///Returns true if the referenced value is in the set, false otherwise.
+    fn contains<Q: ?Sized + Eq>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>;
+
+    ///Returns `true` if this set is empty. `false` otherwise.
+    fn is_empty(&self) -> bool;
+
+    ///The number of items contained in this set.
+    fn len(&self) -> usize;
+
+    ///Iterates over the values in this set.
+    fn values<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+
+    ///Attempts to remove the referenced value from the set, returning None if it is not present.
+    fn remove<Q: ?Sized + Eq>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>;
+
+    ///Retains only the values for which `f` returns true, removing the rest.
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F);
+
+    ///Returns an iterator which removes and yields every value for which `pred` returns true.
+    ///If the iterator is dropped before it is exhausted, the values it has not visited are kept.
+    fn extract_if<'a, F: FnMut(&T) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a;
+
+    ///Removes every value from the set, returning them in an iterator.
+    fn drain<'a>(&'a mut self) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a;
+}
+
+#[allow(private_bounds)]
+impl<T: Eq, S: FallibleLinearSet<T>> InfallibleLinearSet<T> for S
+where
+    <S::Backing as FallibleLinearMap<T, ()>>::InsertionError: InsertionFailure,
+{
+    fn insert(&mut self, value: T) -> bool {
+        FallibleLinearSet::insert(self, value).unwrap_or_else(|e| e.fail())
+    }
+
+    fn contains<Q: ?Sized + Eq>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        FallibleLinearSet::contains(self, value)
+    }
+
+    fn is_empty(&self) -> bool {
+        FallibleLinearSet::is_empty(self)
+    }
+
+    fn len(&self) -> usize {
+        FallibleLinearSet::len(self)
+    }
+
+    fn values<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        FallibleLinearSet::values(self)
+    }
+
+    fn remove<Q: ?Sized + Eq>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
+        FallibleLinearSet::remove(self, value)
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        FallibleLinearSet::retain(self, f)
+    }
+
+    fn extract_if<'a, F: FnMut(&T) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        FallibleLinearSet::extract_if(self, pred)
+    }
+
+    fn drain<'a>(&'a mut self) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        FallibleLinearSet::drain(self)
+    }
+}
+
+///Implements `Extend` through `FallibleLinearMap::try_extend`, escalating a failed insertion like `insert`.
+///Pairs whose key is already present update the value, as with `insert`.
+macro_rules! extend_map {
+    ($([$($generics:tt)*] $map:ty;)*) => {$(
+        impl<$($generics)*> Extend<(K, V)> for $map {
+            fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+                FallibleLinearMap::try_extend(self, iter).unwrap_or_else(|e| e.into_error().fail())
+            }
+        }
+    )*};
+}
+
+extend_map! {
+    [K: Eq, V] crate::VecMap<K, V>;
+    [K: Ord, V] crate::SortedVecMap<K, V>;
+    [K: Eq, V, P: crate::AccessPolicy] crate::SelfOrganizingVecMap<K, V, P>;
+    [K: Eq, V] crate::SoaVecMap<K, V>;
+    [K: Eq + Hash, V, S: BuildHasher] crate::FingerprintVecMap<K, V, S>;
+    [K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher] crate::AdaptiveMap<K, V, THRESHOLD, S>;
+    [K: Eq, V] crate::DequeMap<K, V>;
+    [K: Eq, V, P: crate::AccessPolicy] crate::SelfOrganizingDequeMap<K, V, P>;
+    [K: Eq, V, const STACK_CAPACITY: usize] crate::FatMap<K, V, STACK_CAPACITY>;
+    [K: Ord, V, const STACK_CAPACITY: usize] crate::SortedFatMap<K, V, STACK_CAPACITY>;
+    [K: Eq, V, const STACK_CAPACITY: usize] crate::SoaFatMap<K, V, STACK_CAPACITY>;
+    [K: Eq, V, const CAPACITY: usize] crate::StackMap<K, V, CAPACITY>;
+    [K: Ord, V, const CAPACITY: usize] crate::SortedStackMap<K, V, CAPACITY>;
+    [K: Eq, V, const CAPACITY: usize] crate::SoaStackMap<K, V, CAPACITY>;
+}
+
+///Implements `Extend` through `FallibleLinearSet::try_extend`, escalating a failed insertion like `insert`.
+///Values which are already present are not replaced, as with `insert`.
+macro_rules! extend_set {
+    ($([$($generics:tt)*] $set:ty;)*) => {$(
+        impl<$($generics)*> Extend<T> for $set {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                FallibleLinearSet::try_extend(self, iter).unwrap_or_else(|e| e.into_error().fail())
+            }
+        }
+    )*};
+}
+
+extend_set! {
+    [T: Eq] VecSet<T>;
+    [T: Eq] DequeSet<T>;
+    [T: Eq, const STACK_CAPACITY: usize] FatSet<T, STACK_CAPACITY>;
+    [T: Eq, const STACK_CAPACITY: usize] StackSet<T, STACK_CAPACITY>;
+}
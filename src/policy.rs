@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+
+///Stores whose elements can be rearranged in place, like the backings of self-organizing and positional maps.
+pub(crate) trait Reorder {
+    ///SAFETY: UB if `a` or `b` is >= the length of the store.
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize);
+
+    ///Moves the element at `index` to the front of the store, shifting every element before it back by one.
+    ///SAFETY: UB if `index` is >= the length of the store.
+    unsafe fn move_to_front_unchecked(&mut self, index: usize);
+}
+
+impl<T> Reorder for Vec<T> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        //in bounds as upheld by the caller, so this cannot panic.
+        self.swap(a, b)
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //SAFETY: upheld by caller
+        unsafe { self.get_unchecked_mut(..=index) }.rotate_right(1)
+    }
+}
+
+impl<T> Reorder for VecDeque<T> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        //in bounds as upheld by the caller, so this cannot panic.
+        self.swap(a, b)
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //only shifts the elements on the shorter side of `index`.
+        //SAFETY: upheld by caller
+        let element = unsafe { self.remove(index).unwrap_unchecked() };
+        //an element was just removed, so this never reallocates.
+        self.push_front(element)
+    }
+}
+
+///Sealed trait which decides how a self-organizing map rearranges its pairs after a successful lookup.
+pub(crate) trait Promote {
+    ///Moves the element at `index`, which was just accessed, towards the front of `store`, returning its new index.
+    ///SAFETY: UB if `index` is >= the length of the store.
+    unsafe fn promote<R: Reorder + ?Sized>(store: &mut R, index: usize) -> usize;
+}
+
+///How a self-organizing map, like `SelfOrganizingVecMap`, rearranges its pairs when a key is found by a reordering lookup.
+///Implemented by `MoveToFront` and `Transpose`.
+#[allow(private_bounds)]
+pub trait AccessPolicy: Promote {}
+
+///Moves a pair to the front of the map whenever it is accessed.
+///Adapts quickly when the set of hot keys changes, but a single access to a cold key pushes every hot key back by one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MoveToFront;
+
+impl Promote for MoveToFront {
+    unsafe fn promote<R: Reorder + ?Sized>(store: &mut R, index: usize) -> usize {
+        //SAFETY: upheld by caller
+        unsafe { store.move_to_front_unchecked(index) };
+        0
+    }
+}
+
+impl AccessPolicy for MoveToFront {}
+
+///Swaps a pair with the one before it whenever it is accessed.
+///Hot keys migrate to the front gradually, which makes the order robust against occasional accesses to cold keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Transpose;
+
+impl Promote for Transpose {
+    unsafe fn promote<R: Reorder + ?Sized>(store: &mut R, index: usize) -> usize {
+        match index {
+            0 => 0,
+            _ => {
+                //SAFETY: index - 1 < index, which is in bounds as upheld by the caller.
+                unsafe { store.swap_unchecked(index - 1, index) };
+                index - 1
+            }
+        }
+    }
+}
+
+impl AccessPolicy for Transpose {}
@@ -0,0 +1,114 @@
+use std::borrow::Borrow;
+
+use crate::{policy::Reorder, FallibleLinearMap};
+
+//sealed trait
+#[allow(private_bounds)]
+///Positional access to maps which keep their pairs in insertion order, in the style of `IndexMap`.
+///Every index based lookup is O(1): only the methods taking a key scan the map.
+pub trait PositionalLinearMap<K: Eq, V>: FallibleLinearMap<K, V> + Reorder {
+    ///Gets the pair at `index`, or None if it is out of bounds.
+    fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        match index < self.len() {
+            //SAFETY: index < len.
+            true => Some(unsafe { self.get_index_unchecked(index) }),
+            false => None,
+        }
+    }
+
+    ///Gets the pair at `index` with a mutable reference to its value, or None if it is out of bounds.
+    fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        match index < self.len() {
+            //SAFETY: index < len.
+            true => Some(unsafe { self.get_index_unchecked_mut(index) }),
+            false => None,
+        }
+    }
+
+    ///Returns the index of the given key, or None if it is not in the map.
+    ///The key may be any borrowed form of the map's key type.
+    fn get_index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        self.index_of(key)
+    }
+
+    ///Removes the pair at `index` by swapping it with the last pair, returning None if it is out of bounds.
+    ///This is O(1), but moves the last pair into the removed pair's position.
+    fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let last = self.len().checked_sub(1)?;
+
+        match index <= last {
+            //SAFETY: index <= last < len.
+            true => unsafe {
+                self.swap_unchecked(index, last);
+                Some(self.remove_index_unchecked(last))
+            },
+            false => None,
+        }
+    }
+
+    ///Removes the value associated with the given key by swapping its pair with the last pair,
+    ///returning None if it is not found. See `swap_remove_index`.
+    fn swap_remove<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.get_index_of(key)?;
+        self.swap_remove_index(index).map(|(_, v)| v)
+    }
+
+    ///Removes the pair at `index` by shifting every pair after it, returning None if it is out of bounds.
+    ///This preserves the order of the remaining pairs, but is O(n).
+    fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        match index < self.len() {
+            //SAFETY: index < len.
+            true => Some(unsafe { self.remove_index_unchecked(index) }),
+            false => None,
+        }
+    }
+
+    ///Removes the value associated with the given key by shifting every pair after it,
+    ///returning None if it is not found. See `shift_remove_index`.
+    fn shift_remove<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.get_index_of(key)?;
+        self.shift_remove_index(index).map(|(_, v)| v)
+    }
+
+    ///Moves the pair at `from` to `to`, shifting the pairs in between.
+    ///Returns false, leaving the map untouched, if either index is out of bounds.
+    fn move_index(&mut self, from: usize, to: usize) -> bool {
+        if from >= self.len() || to >= self.len() {
+            return false;
+        }
+
+        match from < to {
+            true => (from..to).for_each(|i| {
+                //SAFETY: i < i + 1 <= to < len.
+                unsafe { self.swap_unchecked(i, i + 1) }
+            }),
+            false => (to..from).rev().for_each(|i| {
+                //SAFETY: i < i + 1 <= from < len.
+                unsafe { self.swap_unchecked(i, i + 1) }
+            }),
+        }
+        true
+    }
+
+    ///Swaps the pairs at `a` and `b`.
+    ///Returns false, leaving the map untouched, if either index is out of bounds.
+    fn swap_indices(&mut self, a: usize, b: usize) -> bool {
+        match a < self.len() && b < self.len() {
+            true => {
+                //SAFETY: both indices are < len.
+                unsafe { self.swap_unchecked(a, b) };
+                true
+            }
+            false => false,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    array,
+    borrow::Borrow,
+    hint,
+    simd::{cmp::SimdPartialEq, Simd},
+};
+
+///Finds keys within the pairs backing a map.
+///
+///Every key type is searched with a linear scan, except primitive integers and `char`s, which are compared several at a
+///time with SIMD. That fast path is selected through specialization, so it is applied automatically whenever the borrowed
+///form of the key is the key type itself.
+///
+///`ArrayMap`'s length is known at compile time, so it searches through `position_in_array`, which compares every chunk
+///of keys without branching on the results. `StackMap` only knows its `CAPACITY` at compile time: the slots past its
+///length are uninitialized and can't be compared, so it searches its initialized pairs as a slice, stopping at the first
+///chunk holding the key. `benches/key_search.rs` compares the two searches over the same `ArrayMap`.
+///
+///`usize` and `isize` cover pointer sized keys. Raw pointers are not specialized for: `min_specialization` can't
+///specialize an impl which names the pointee twice, as `KeySearch<*const T> for *const T` would. Key maps by
+///`pointer.addr()` to search them with SIMD.
+pub(crate) trait KeySearch<Q: ?Sized> {
+    ///Returns the index of the first pair whose key is equal to `key`.
+    fn position<V>(pairs: &[(Self, V)], key: &Q) -> Option<usize>
+    where
+        Self: Sized;
+
+    ///Returns the index of the first key equal to `key`, for maps which store their keys apart from their values.
+    ///Those keys are loaded into vectors directly, rather than gathered out of pairs.
+    fn position_in_keys(keys: &[Self], key: &Q) -> Option<usize>
+    where
+        Self: Sized;
+
+    ///Returns the index of the first pair whose key is equal to `key`, for maps whose length is known at compile time.
+    ///Primitive keys compare every chunk, keeping the mask of the earliest one with a match, and take a single
+    ///`trailing_zeros` at the end. Nothing depends on where the key is, so the constant number of chunks is unrolled.
+    fn position_in_array<V, const LENGTH: usize>(
+        pairs: &[(Self, V); LENGTH],
+        key: &Q,
+    ) -> Option<usize>
+    where
+        Self: Sized;
+}
+
+impl<K: Borrow<Q>, Q: ?Sized + Eq> KeySearch<Q> for K {
+    default fn position<V>(pairs: &[(Self, V)], key: &Q) -> Option<usize> {
+        pairs.iter().position(|(k, _)| k.borrow() == key)
+    }
+
+    default fn position_in_keys(keys: &[Self], key: &Q) -> Option<usize> {
+        keys.iter().position(|k| k.borrow() == key)
+    }
+
+    default fn position_in_array<V, const LENGTH: usize>(
+        pairs: &[(Self, V); LENGTH],
+        key: &Q,
+    ) -> Option<usize> {
+        pairs.iter().position(|(k, _)| k.borrow() == key)
+    }
+}
+
+macro_rules! simd_key_search {
+    ($($key:ty => $lane:ty, $lanes:literal;)*) => {$(
+        impl KeySearch<$key> for $key {
+            fn position<V>(pairs: &[(Self, V)], key: &$key) -> Option<usize> {
+                simd_key_search!(@search pairs, key, $lane, $lanes, |(k, _): &(Self, V)| *k)
+            }
+
+            fn position_in_keys(keys: &[Self], key: &$key) -> Option<usize> {
+                simd_key_search!(@search keys, key, $lane, $lanes, |k: &Self| *k)
+            }
+
+            fn position_in_array<V, const LENGTH: usize>(
+                pairs: &[(Self, V); LENGTH],
+                key: &$key,
+            ) -> Option<usize> {
+                simd_key_search!(@unrolled pairs, key, $lane, $lanes, |(k, _): &(Self, V)| *k)
+            }
+        }
+    )*};
+
+    (@search $items:ident, $key:ident, $lane:ty, $lanes:literal, $get:expr) => {{
+        let get = $get;
+        let needle = Simd::<$lane, $lanes>::splat(*$key as $lane);
+        let (chunks, tail) = $items.as_chunks::<$lanes>();
+
+        for (c, chunk) in chunks.iter().enumerate() {
+            let keys = Simd::from_array(array::from_fn(|i| get(&chunk[i]) as $lane));
+
+            match keys.simd_eq(needle).to_bitmask() {
+                0 => continue,
+                mask => return Some(c * $lanes + mask.trailing_zeros() as usize),
+            }
+        }
+
+        tail.iter()
+            .position(|item| get(item) == *$key)
+            .map(|i| chunks.len() * $lanes + i)
+    }};
+
+    (@unrolled $items:ident, $key:ident, $lane:ty, $lanes:literal, $get:expr) => {{
+        let get = $get;
+        let needle = Simd::<$lane, $lanes>::splat(*$key as $lane);
+        let (chunks, tail) = $items.as_chunks::<$lanes>();
+
+        //the tail follows every chunk, so its matches are only kept if no chunk has any.
+        let tail_mask = tail
+            .iter()
+            .enumerate()
+            .fold(0u64, |mask, (i, item)| mask | ((get(item) == *$key) as u64) << i);
+
+        //walks the chunks back to front, so that the earliest chunk with a match is the last one selected.
+        let (chunk, mask) = chunks.iter().enumerate().rev().fold(
+            (chunks.len(), tail_mask),
+            |found, (c, chunk)| {
+                let keys = Simd::from_array(array::from_fn(|i| get(&chunk[i]) as $lane));
+                let mask = keys.simd_eq(needle).to_bitmask();
+                hint::select_unpredictable(mask != 0, (c, mask), found)
+            },
+        );
+
+        match mask {
+            0 => None,
+            mask => Some(chunk * $lanes + mask.trailing_zeros() as usize),
+        }
+    }};
+}
+
+//lane counts fill a 128 bit vector, which every target with SIMD support provides.
+simd_key_search! {
+    u8 => u8, 16;
+    i8 => i8, 16;
+    u16 => u16, 8;
+    i16 => i16, 8;
+    u32 => u32, 4;
+    i32 => i32, 4;
+    char => u32, 4;
+    u64 => u64, 2;
+    i64 => i64, 2;
+    usize => usize, 2;
+    isize => isize, 2;
+}
@@ -0,0 +1,59 @@
+//!Modules for `#[serde(with = "...")]` choosing what happens when a map's input repeats a key.
+//!Each works with any map in this crate:
+//!
+//!```ignore
+//!#[derive(serde::Deserialize, serde::Serialize)]
+//!struct Config {
+//!    #[serde(with = "linear_collections::serde::map::reject_duplicates")]
+//!    headers: VecMap<String, String>,
+//!}
+//!```
+//!
+//!Serialization is unchanged by every policy.
+
+///Generates a module whose `deserialize` handles repeated keys as `$duplicates` says.
+macro_rules! policy {
+    ($(#[$doc:meta])* $name:ident, $duplicates:ident) => {
+        $(#[$doc])*
+        pub mod $name {
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            use crate::{serde::Duplicates, FallibleLinearMap};
+
+            pub fn deserialize<'de, D, K, V, M>(deserializer: D) -> Result<M, D::Error>
+            where
+                D: Deserializer<'de>,
+                K: Eq + Deserialize<'de>,
+                V: Deserialize<'de>,
+                M: FallibleLinearMap<K, V> + Default,
+            {
+                crate::serde::deserialize_map_with(deserializer, Duplicates::$duplicates)
+            }
+
+            pub fn serialize<S: Serializer, M: Serialize>(
+                map: &M,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                map.serialize(serializer)
+            }
+        }
+    };
+}
+
+policy!(
+    ///Fails deserialization if a key is repeated.
+    reject_duplicates,
+    Reject
+);
+
+policy!(
+    ///Keeps the value of the first pair with each key, discarding the values of the later ones.
+    keep_first,
+    KeepFirst
+);
+
+policy!(
+    ///Keeps the value of the last pair with each key. This is what the `Deserialize` impls of the maps do.
+    keep_last,
+    KeepLast
+);
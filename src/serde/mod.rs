@@ -1,15 +1,21 @@
-/*
+//!Serde support for every map and set, enabled by the "serde" feature.
+//!
+//!Maps and sets deserialize like the std collections: a repeated key replaces the earlier value, and a repeated
+//!value in a set is ignored. The `map` and `set` modules offer other policies for use with `#[serde(with = "...")]`.
+pub mod map;
+pub mod set;
 #[cfg(test)]
 mod test;
-*/
+
 use std::marker::PhantomData;
 
 use serde::{
+    de::{Error, MapAccess, SeqAccess, Visitor},
     ser::{SerializeMap, SerializeSeq},
-    Deserialize, Serialize, Serializer,
+    Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use crate::{FallibleLinearMap, FallibleLinearSet};
+use crate::{FallibleLinearMap, FallibleLinearSet, MapIndexMut};
 
 pub(crate) fn serialize_fallible_map<
     'a,
@@ -48,45 +54,163 @@ pub(crate) fn serialize_fallible_set<
     serializer.end()
 }
 
-struct MapVisitor<
-    'de,
-    K: Eq + Deserialize<'de>,
-    V: Sized + PartialEq + Deserialize<'de>,
-    M: FallibleLinearMap<K, V>,
-> {
+///Upper bound on the memory reserved up front from a `size_hint`, as the hint comes from the input and can't be trusted.
+///Past this, the collection grows as pairs arrive and fails only if it really runs out of room.
+const MAX_PREALLOCATION: usize = 1024 * 1024;
+
+///Caps `hint` so that reserving room for that many `T`s takes at most `MAX_PREALLOCATION` bytes.
+fn cautious<T>(hint: Option<usize>) -> usize {
+    hint.unwrap_or(0)
+        .min(MAX_PREALLOCATION / std::mem::size_of::<T>().max(1))
+}
+
+///What to do when a key, or a set's value, is repeated in the input.
+#[derive(Clone, Copy)]
+pub(crate) enum Duplicates {
+    ///Fail deserialization.
+    Reject,
+    ///Keep the first pair with the key, discarding the later ones.
+    KeepFirst,
+    ///Keep the last pair with the key, as `insert` does.
+    KeepLast,
+}
+
+struct MapVisitor<K, V, M> {
     marker: PhantomData<fn() -> M>,
-    use_generics: PhantomData<(&'de str, K, V)>,
+    pairs: PhantomData<fn() -> (K, V)>,
+    duplicates: Duplicates,
 }
 
-/*
-impl<'de, K: Eq + Deserialize<'de>, V: Eq + Deserialize<'de>, M: FallibleLinearMap<K, V>>
-    Visitor<'de> for MapVisitor<'de, K, V, M>
+impl<'de, K: Eq + Deserialize<'de>, V: Deserialize<'de>, M: FallibleLinearMap<K, V> + Default>
+    Visitor<'de> for MapVisitor<K, V, M>
 {
     type Value = M;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("map")
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<M, A::Error> {
+        let mut map = M::default();
+        map.reserve_hint(cautious::<(K, V)>(access.size_hint()))
+            .map_err(A::Error::custom)?;
+
+        //neither `insert` nor `insert_if_vacant` reaches the pair by index, which is slow for maps like `AdaptiveMap`.
+        let mut position = 0;
+        while let Some((k, v)) = access.next_entry()? {
+            let inserted = match self.duplicates {
+                Duplicates::KeepLast => map.insert(k, v).map(|_| true),
+                Duplicates::Reject | Duplicates::KeepFirst => map.insert_if_vacant(k, v),
+            }
+            .map_err(A::Error::custom)?;
+
+            if let (false, Duplicates::Reject) = (inserted, self.duplicates) {
+                return Err(A::Error::custom(format_args!(
+                    "duplicate key at entry {position}"
+                )));
+            }
+            position += 1;
+        }
+
+        Ok(map)
     }
 }
 
+///Deserializes any map from a serde map. As with `insert`, a repeated key replaces the value of the earlier one.
+///Failing to allocate, or running out of room in a stack allocated map, is reported as a deserialization error.
 pub(crate) fn deserialize_fallible_map<
-    'a,
-    D: Deserializer<'a>,
-    K: Eq + Deserialize<'a>,
-    V: PartialEq + Deserialize<'a>,
-    M: FallibleLinearMap<K, V>,
+    'de,
+    D: Deserializer<'de>,
+    K: Eq + Deserialize<'de>,
+    V: Deserialize<'de>,
+    M: FallibleLinearMap<K, V> + Default,
 >(
-    fallible_map: &M,
     deserializer: D,
 ) -> Result<M, D::Error> {
-    let mut deserializer = deserializer.deserialize_map(MapVisitor {
-        marker: PhantomData::default(),
-        use_generics: PhantomData::default(),
-    });
+    deserialize_map_with(deserializer, Duplicates::KeepLast)
+}
 
-    for (k, v) in fallible_map.iter() {
-        serializer.serialize_entry(k, v)?;
+///Deserializes any map from a serde map, handling repeated keys as `duplicates` says.
+pub(crate) fn deserialize_map_with<
+    'de,
+    D: Deserializer<'de>,
+    K: Eq + Deserialize<'de>,
+    V: Deserialize<'de>,
+    M: FallibleLinearMap<K, V> + Default,
+>(
+    deserializer: D,
+    duplicates: Duplicates,
+) -> Result<M, D::Error> {
+    deserializer.deserialize_map(MapVisitor {
+        marker: PhantomData,
+        pairs: PhantomData,
+        duplicates,
+    })
+}
+
+struct SetVisitor<T, S> {
+    marker: PhantomData<fn() -> S>,
+    values: PhantomData<fn() -> T>,
+    ///Sets only distinguish `Reject` from the rest, as they never replace a value which is already present.
+    duplicates: Duplicates,
+}
+
+impl<'de, T: Eq + Deserialize<'de>, S: FallibleLinearSet<T> + Default> Visitor<'de>
+    for SetVisitor<T, S>
+{
+    type Value = S;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a sequence")
     }
 
-    serializer.end()
-}*/
+    fn visit_seq<A: SeqAccess<'de>>(self, mut access: A) -> Result<S, A::Error> {
+        let mut set = S::default();
+        set.map_mut()
+            .reserve_hint(cautious::<T>(access.size_hint()))
+            .map_err(A::Error::custom)?;
+
+        let mut position = 0;
+        while let Some(value) = access.next_element()? {
+            let inserted = set.insert(value).map_err(A::Error::custom)?;
+            if let (false, Duplicates::Reject) = (inserted, self.duplicates) {
+                return Err(A::Error::custom(format_args!(
+                    "duplicate value at element {position}"
+                )));
+            }
+            position += 1;
+        }
+
+        Ok(set)
+    }
+}
+
+///Deserializes any set from a serde sequence. As with `insert`, repeated values are ignored.
+///Failing to allocate, or running out of room in a stack allocated set, is reported as a deserialization error.
+pub(crate) fn deserialize_fallible_set<
+    'de,
+    D: Deserializer<'de>,
+    T: Eq + Deserialize<'de>,
+    S: FallibleLinearSet<T> + Default,
+>(
+    deserializer: D,
+) -> Result<S, D::Error> {
+    deserialize_set_with(deserializer, Duplicates::KeepFirst)
+}
+
+///Deserializes any set from a serde sequence, failing on a repeated value if `duplicates` is `Reject`.
+pub(crate) fn deserialize_set_with<
+    'de,
+    D: Deserializer<'de>,
+    T: Eq + Deserialize<'de>,
+    S: FallibleLinearSet<T> + Default,
+>(
+    deserializer: D,
+    duplicates: Duplicates,
+) -> Result<S, D::Error> {
+    deserializer.deserialize_seq(SetVisitor {
+        marker: PhantomData,
+        values: PhantomData,
+        duplicates,
+    })
+}
@@ -0,0 +1,43 @@
+//!Modules for `#[serde(with = "...")]` choosing what happens when a set's input repeats a value.
+//!Each works with any set in this crate. Serialization is unchanged by every policy.
+
+///Generates a module whose `deserialize` handles repeated values as `$duplicates` says.
+macro_rules! policy {
+    ($(#[$doc:meta])* $name:ident, $duplicates:ident) => {
+        $(#[$doc])*
+        pub mod $name {
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            use crate::{serde::Duplicates, FallibleLinearSet};
+
+            pub fn deserialize<'de, D, T, S>(deserializer: D) -> Result<S, D::Error>
+            where
+                D: Deserializer<'de>,
+                T: Eq + Deserialize<'de>,
+                S: FallibleLinearSet<T> + Default,
+            {
+                crate::serde::deserialize_set_with(deserializer, Duplicates::$duplicates)
+            }
+
+            pub fn serialize<Z: Serializer, S: Serialize>(
+                set: &S,
+                serializer: Z,
+            ) -> Result<Z::Ok, Z::Error> {
+                set.serialize(serializer)
+            }
+        }
+    };
+}
+
+policy!(
+    ///Fails deserialization if a value is repeated.
+    reject_duplicates,
+    Reject
+);
+
+policy!(
+    ///Keeps the first of each repeated value, discarding the later ones. This is what the `Deserialize` impls of
+    ///the sets do.
+    ignore_duplicates,
+    KeepFirst
+);
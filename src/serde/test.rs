@@ -1,12 +1,14 @@
-use crate::panicking::*;
-use serde_test::assert_tokens;
+use crate::{
+    AdaptiveMap, DequeMap, DequeSet, FallibleLinearMap, FallibleLinearSet, FatMap, FatSet,
+    LinearMapView, SortedStackMap, SortedVecMap, StackMap, StackSet, TryFromIterator, VecMap,
+    VecSet,
+};
+use serde::de::value::{Error, MapDeserializer, SeqDeserializer};
 use serde_test::Token;
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens};
 #[test]
 fn vec_map_many() {
-    let mut map: VecMap<char, i32> = VecMap::new();
-    map.insert('b', 20);
-    map.insert('a', 10);
-    map.insert('c', 30);
+    let map = VecMap::try_from_iter([('b', 20), ('a', 10), ('c', 30)]).unwrap();
 
     assert_tokens(
         &map,
@@ -32,11 +34,7 @@ fn vec_map_empty() {
 
 #[test]
 fn vec_set_many() {
-    let mut set: VecSet<char> = VecSet::new();
-
-    set.insert('b');
-    set.insert('a');
-    set.insert('c');
+    let set = VecSet::try_from_iter(['b', 'a', 'c']).unwrap();
 
     assert_tokens(
         &set,
@@ -56,3 +54,172 @@ fn vec_set_empty() {
 
     assert_tokens(&set, &[Token::Seq { len: Some(0) }, Token::SeqEnd]);
 }
+
+fn map_tokens() -> [Token; 8] {
+    [
+        Token::Map { len: Some(3) },
+        Token::Char('b'),
+        Token::I32(20),
+        Token::Char('a'),
+        Token::I32(10),
+        Token::Char('c'),
+        Token::I32(30),
+        Token::MapEnd,
+    ]
+}
+
+#[test]
+fn every_map_round_trips() {
+    fn round_trip<M>(map: M)
+    where
+        M: FallibleLinearMap<char, i32>
+            + serde::Serialize
+            + for<'de> serde::Deserialize<'de>
+            + PartialEq
+            + std::fmt::Debug,
+    {
+        assert_tokens(&map, &map_tokens());
+    }
+
+    fn filled<M: FallibleLinearMap<char, i32> + Default>() -> M {
+        let mut map = M::default();
+        map.insert('b', 20).unwrap();
+        map.insert('a', 10).unwrap();
+        map.insert('c', 30).unwrap();
+        map
+    }
+
+    round_trip(filled::<VecMap<_, _>>());
+    round_trip(filled::<DequeMap<_, _>>());
+    round_trip(filled::<FatMap<_, _, 2>>());
+    round_trip(filled::<StackMap<_, _, 3>>());
+    round_trip(filled::<AdaptiveMap<_, _, 8>>());
+}
+
+#[test]
+fn sorted_map_sorts_deserialized_pairs() {
+    let map = SortedVecMap::<char, i32>::try_from_iter([('a', 10), ('b', 20), ('c', 30)]).unwrap();
+    assert_de_tokens(&map, &map_tokens());
+
+    let stack = SortedStackMap::<char, i32, 3>::try_from_iter(map).unwrap();
+    assert_de_tokens(&stack, &map_tokens());
+}
+
+#[test]
+fn repeated_key_keeps_last_value() {
+    let map = VecMap::try_from_iter([('a', 2)]).unwrap();
+
+    assert_de_tokens(
+        &map,
+        &[
+            Token::Map { len: Some(2) },
+            Token::Char('a'),
+            Token::I32(1),
+            Token::Char('a'),
+            Token::I32(2),
+            Token::MapEnd,
+        ],
+    );
+}
+
+#[test]
+fn full_stack_map_is_an_error() {
+    assert_de_tokens_error::<StackMap<char, i32, 2>>(&map_tokens(), "push would exceed capacity");
+    assert_de_tokens_error::<StackSet<char, 1>>(
+        &[Token::Seq { len: None }, Token::Char('a'), Token::Char('b')],
+        "push would exceed capacity",
+    );
+}
+
+#[test]
+fn every_set_round_trips() {
+    let tokens = [
+        Token::Seq { len: Some(2) },
+        Token::Char('b'),
+        Token::Char('a'),
+        Token::SeqEnd,
+    ];
+
+    let vec = VecSet::try_from_iter(['b', 'a']).unwrap();
+    assert_tokens(&vec, &tokens);
+    assert_tokens(&DequeSet::try_from_iter(['b', 'a']).unwrap(), &tokens);
+    assert_tokens(&FatSet::<_, 1>::try_from_iter(['b', 'a']).unwrap(), &tokens);
+    assert_tokens(
+        &StackSet::<_, 2>::try_from_iter(['b', 'a']).unwrap(),
+        &tokens,
+    );
+
+    //repeated values are ignored.
+    assert_de_tokens(
+        &vec,
+        &[
+            Token::Seq { len: Some(3) },
+            Token::Char('b'),
+            Token::Char('a'),
+            Token::Char('b'),
+            Token::SeqEnd,
+        ],
+    );
+}
+
+fn repeated_pairs() -> MapDeserializer<'static, std::vec::IntoIter<(char, i32)>, Error> {
+    MapDeserializer::new(vec![('a', 1), ('b', 2), ('a', 3)].into_iter())
+}
+
+#[test]
+fn map_duplicate_policies() {
+    let first: VecMap<char, i32> = super::map::keep_first::deserialize(repeated_pairs()).unwrap();
+    assert!(first.iter().eq([(&'a', &1), (&'b', &2)]));
+
+    let last: FatMap<char, i32, 2> = super::map::keep_last::deserialize(repeated_pairs()).unwrap();
+    assert!(last.iter().eq([(&'a', &3), (&'b', &2)]));
+
+    let rejected: Result<StackMap<char, i32, 4>, _> =
+        super::map::reject_duplicates::deserialize(repeated_pairs());
+    assert_eq!(
+        rejected.unwrap_err().to_string(),
+        "duplicate key at entry 2"
+    );
+
+    let unique: DequeMap<char, i32> = super::map::reject_duplicates::deserialize(
+        MapDeserializer::<_, Error>::new(vec![('a', 1), ('b', 2)].into_iter()),
+    )
+    .unwrap();
+    assert_eq!(unique.len(), 2);
+}
+
+#[test]
+fn set_duplicate_policies() {
+    let values = || SeqDeserializer::<_, Error>::new(vec!['a', 'b', 'a'].into_iter());
+
+    let ignored: VecSet<char> = super::set::ignore_duplicates::deserialize(values()).unwrap();
+    assert!(ignored.values().eq(&['a', 'b']));
+
+    let rejected: Result<StackSet<char, 4>, _> =
+        super::set::reject_duplicates::deserialize(values());
+    assert_eq!(
+        rejected.unwrap_err().to_string(),
+        "duplicate value at element 2"
+    );
+}
+
+#[test]
+fn adaptive_map_duplicate_policies() {
+    let pairs = || {
+        MapDeserializer::<_, Error>::new((0..100).chain(0..100).enumerate().map(|(n, k)| (k, n)))
+    };
+
+    let first: AdaptiveMap<i32, usize, 8> = super::map::keep_first::deserialize(pairs()).unwrap();
+    assert!(first.is_promoted());
+    assert!((0..100).all(|k| first.get(&k) == Some(&(k as usize))));
+
+    let last: AdaptiveMap<i32, usize, 8> = super::map::keep_last::deserialize(pairs()).unwrap();
+    assert!((0..100).all(|k| last.get(&k) == Some(&(k as usize + 100))));
+
+    let rejected: Result<AdaptiveMap<i32, usize, 8>, _> =
+        super::map::reject_duplicates::deserialize(pairs());
+    assert_eq!(
+        rejected.unwrap_err().to_string(),
+        "duplicate key at entry 100"
+    );
+}
@@ -0,0 +1,181 @@
+use std::{
+    marker::PhantomData,
+    ptr::{copy, NonNull},
+};
+
+///Sealed trait for the stores holding one column of a struct-of-arrays map, like `SoaVecMap`.
+///Exposes just enough of the store to move its elements around in lockstep with a second column.
+pub(crate) trait Column {
+    type Item;
+    ///Pointers to the start of the store, from which the address of every slot is worked out.
+    type Slots: Copy;
+
+    fn len(&self) -> usize;
+
+    ///Sets the length of the store without dropping or initializing any element.
+    ///SAFETY: UB if `len` exceeds the capacity of the store, or if any element below `len` is uninitialized once the store
+    ///is next used.
+    unsafe fn set_len(&mut self, len: usize);
+
+    ///Returns pointers to the start of the store. Stores may hold their elements inline, so reborrowing the store
+    ///invalidates pointers taken from it earlier: callers take these once, and find every slot through them.
+    fn slots(&mut self) -> Self::Slots;
+
+    ///Returns a raw pointer to the slot at `index`, which may or may not be initialized.
+    ///SAFETY: UB if `index` is >= the capacity of the store `slots` was taken from, or if the store has been
+    ///used mutably since.
+    unsafe fn slot(slots: Self::Slots, index: usize) -> *mut Self::Item;
+}
+
+impl<T> Column for Vec<T> {
+    type Item = T;
+    type Slots = *mut T;
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    unsafe fn set_len(&mut self, len: usize) {
+        //SAFETY: upheld by caller
+        unsafe { Vec::set_len(self, len) }
+    }
+
+    fn slots(&mut self) -> *mut T {
+        self.as_mut_ptr()
+    }
+
+    unsafe fn slot(slots: *mut T, index: usize) -> *mut T {
+        //SAFETY: upheld by caller
+        unsafe { slots.add(index) }
+    }
+}
+
+///Removes and yields every pair of a keys and a values column for which `pred` returns true, compacting the kept pairs
+///of both columns in a single pass. Pairs which have not been visited when the iterator is dropped are kept.
+pub(crate) struct ExtractPairs<
+    'a,
+    KC: Column,
+    VC: Column,
+    F: FnMut(&KC::Item, &mut VC::Item) -> bool,
+> {
+    ///The columns are held as raw pointers, as moving a `&mut` to them would invalidate the slots taken from them.
+    keys: NonNull<KC>,
+    values: NonNull<VC>,
+    ///Taken once, as the columns may not be reborrowed while pointers into them are in use.
+    key_slots: KC::Slots,
+    value_slots: VC::Slots,
+    columns: PhantomData<(&'a mut KC, &'a mut VC)>,
+    pred: F,
+    ///Index of the next pair to test.
+    read: usize,
+    ///Number of pairs kept so far. These have all been shifted to the front of both columns.
+    write: usize,
+    ///The length of the columns before extraction began.
+    original_len: usize,
+}
+
+impl<'a, KC: Column, VC: Column, F: FnMut(&KC::Item, &mut VC::Item) -> bool>
+    ExtractPairs<'a, KC, VC, F>
+{
+    ///SAFETY: UB if the columns are not the same length.
+    pub(crate) unsafe fn new(keys: &'a mut KC, values: &'a mut VC, pred: F) -> Self {
+        let original_len = keys.len();
+
+        //If the iterator is leaked the pairs are leaked with it, rather than being dropped twice.
+        //SAFETY: 0 is always a valid length.
+        unsafe {
+            keys.set_len(0);
+            values.set_len(0);
+        }
+
+        let (mut keys, mut values) = (NonNull::from(keys), NonNull::from(values));
+
+        Self {
+            //SAFETY: both point to columns borrowed for `'a`. The slots are taken last, so nothing invalidates them.
+            key_slots: unsafe { keys.as_mut() }.slots(),
+            value_slots: unsafe { values.as_mut() }.slots(),
+            keys,
+            values,
+            columns: PhantomData,
+            pred,
+            read: 0,
+            write: 0,
+            original_len,
+        }
+    }
+}
+
+impl<'a, KC: Column, VC: Column, F: FnMut(&KC::Item, &mut VC::Item) -> bool> Iterator
+    for ExtractPairs<'a, KC, VC, F>
+{
+    type Item = (KC::Item, VC::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.read < self.original_len {
+            //SAFETY: everything in `read..original_len` is initialized and untouched in both columns.
+            let (key, value) = unsafe {
+                (
+                    KC::slot(self.key_slots, self.read),
+                    VC::slot(self.value_slots, self.read),
+                )
+            };
+            let extract = (self.pred)(unsafe { &*key }, unsafe { &mut *value });
+
+            self.read += 1;
+
+            match extract {
+                //SAFETY: `read` has moved past these slots, so they will never be read again.
+                true => return Some(unsafe { (key.read(), value.read()) }),
+                false => {
+                    //SAFETY: `write` < `read`, so the slots at `write` have either been moved out of or are these slots.
+                    //Columns need not be contiguous, so pairs are moved one at a time.
+                    unsafe {
+                        copy(key, KC::slot(self.key_slots, self.write), 1);
+                        copy(value, VC::slot(self.value_slots, self.write), 1);
+                    }
+                    self.write += 1;
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.original_len - self.read))
+    }
+}
+
+impl<'a, KC: Column, VC: Column, F: FnMut(&KC::Item, &mut VC::Item) -> bool> Drop
+    for ExtractPairs<'a, KC, VC, F>
+{
+    fn drop(&mut self) {
+        let tail = self.original_len - self.read;
+
+        //shift the unvisited pairs left to sit directly after the kept pairs.
+        if self.read != self.write {
+            for offset in 0..tail {
+                //SAFETY: both indices are below `original_len`, and the destinations have been moved out of.
+                unsafe {
+                    copy(
+                        KC::slot(self.key_slots, self.read + offset),
+                        KC::slot(self.key_slots, self.write + offset),
+                        1,
+                    );
+                    copy(
+                        VC::slot(self.value_slots, self.read + offset),
+                        VC::slot(self.value_slots, self.write + offset),
+                        1,
+                    );
+                }
+            }
+        }
+
+        //SAFETY: every slot below this length is initialized in both columns, which are borrowed for `'a`
+        //and no longer reached through their slots.
+        unsafe {
+            self.keys.as_mut().set_len(self.write + tail);
+            self.values.as_mut().set_len(self.write + tail);
+        }
+    }
+}
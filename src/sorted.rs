@@ -0,0 +1,110 @@
+use std::{
+    borrow::Borrow,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::FallibleLinearMap;
+
+///Returns the index of the first pair for which `pred` is false, assuming `pred` is true for every pair before it.
+pub(crate) fn partition_point<K: Eq, V, M: FallibleLinearMap<K, V> + ?Sized>(
+    map: &M,
+    mut pred: impl FnMut(&K) -> bool,
+) -> usize {
+    let (mut low, mut high) = (0, map.len());
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+
+        //SAFETY: low <= mid < high <= len.
+        match pred(unsafe { map.get_index_unchecked(mid) }.0) {
+            true => low = mid + 1,
+            false => high = mid,
+        }
+    }
+
+    low
+}
+
+///Binary searches a sorted map for `key`.
+///Returns `Ok` with the index of the key if it is present, else `Err` with the index it should be inserted at.
+pub(crate) fn search<K, V, M, Q>(map: &M, key: &Q) -> Result<usize, usize>
+where
+    K: Ord + Borrow<Q>,
+    M: FallibleLinearMap<K, V> + ?Sized,
+    Q: ?Sized + Ord,
+{
+    let index = partition_point(map, |k| k.borrow() < key);
+
+    //SAFETY: the short circuit guarantees index < len.
+    match index < map.len() && unsafe { map.get_index_unchecked(index) }.0.borrow() == key {
+        true => Ok(index),
+        false => Err(index),
+    }
+}
+
+///Maps whose pairs are kept in ascending order of their keys.
+///
+///Their inherent `get`, `get_mut`, `contains_key`, `remove`, `remove_entry` and `entry` methods binary search the map,
+///as do their `Index` impls and the `insert` and `entry` methods of `FallibleLinearMap`, which take an owned key.
+///
+///Trait methods looking up a borrowed key, such as `LinearMapView::get`, `LinearMapValuesMut::get_mut` and
+///`FallibleLinearMap::remove_entry`, only require `Q: Eq`. They cannot binary search, so they stay linear scans
+///even on a sorted map: call the inherent methods, which require `Q: Ord`, for O(log n) lookups.
+pub trait SortedLinearMap<K: Ord, V>: FallibleLinearMap<K, V> {
+    ///Returns the pair with the smallest key, or None if the map is empty.
+    fn first(&self) -> Option<(&K, &V)> {
+        match self.is_empty() {
+            true => None,
+            //SAFETY: the map is not empty.
+            false => Some(unsafe { self.get_index_unchecked(0) }),
+        }
+    }
+
+    ///Returns the pair with the largest key, or None if the map is empty.
+    fn last(&self) -> Option<(&K, &V)> {
+        match self.is_empty() {
+            true => None,
+            //SAFETY: the map is not empty.
+            false => Some(unsafe { self.get_index_unchecked(self.len() - 1) }),
+        }
+    }
+
+    ///Returns the pair with the smallest key which is greater than or equal to `key`, or None if there is no such pair.
+    fn lower_bound<Q: ?Sized + Ord>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        let index = partition_point(self, |k| k.borrow() < key);
+
+        match index < self.len() {
+            //SAFETY: index < len.
+            true => Some(unsafe { self.get_index_unchecked(index) }),
+            false => None,
+        }
+    }
+
+    ///Iterates, in ascending order, over the pairs whose keys are within `range`.
+    fn range<'a, Q: ?Sized + Ord, R: RangeBounds<Q>>(
+        &'a self,
+        range: R,
+    ) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a + Borrow<Q>,
+        V: 'a,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(q) => partition_point(self, |k| k.borrow() < q),
+            Bound::Excluded(q) => partition_point(self, |k| k.borrow() <= q),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(q) => partition_point(self, |k| k.borrow() <= q),
+            Bound::Excluded(q) => partition_point(self, |k| k.borrow() < q),
+            Bound::Unbounded => self.len(),
+        };
+
+        //an inverted range is empty.
+        //SAFETY: start <= end <= len.
+        (start..end.max(start)).map(move |i| unsafe { self.get_index_unchecked(i) })
+    }
+}
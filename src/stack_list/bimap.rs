@@ -0,0 +1,86 @@
+use std::collections::TryReserveError;
+
+use crate::{
+    bimap::BiMapIndexMut,
+    stack_list::{error::PushError, StackList},
+    FallibleLinearBiMap, TryClone, TryExtendError, TryFromIterator,
+};
+
+///A bidirectional map backed by a `StackList`, which can be searched by either value of its pairs.
+///Holds at most `CAPACITY` pairs, without allocating.
+#[derive(Debug, Clone)]
+pub struct BiStackMap<L: Eq, R: Eq, const CAPACITY: usize> {
+    stack_list: StackList<(L, R), CAPACITY>,
+}
+
+impl<L: Eq, R: Eq, const CAPACITY: usize> BiStackMap<L, R, CAPACITY> {
+    pub fn new() -> Self {
+        Self {
+            stack_list: StackList::new(),
+        }
+    }
+}
+
+impl<L: Eq, R: Eq, const CAPACITY: usize> Default for BiStackMap<L, R, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L: Eq, R: Eq, const CAPACITY: usize> FallibleLinearBiMap<L, R> for BiStackMap<L, R, CAPACITY> {
+    type Backing = StackList<(L, R), CAPACITY>;
+    type InsertionError = PushError;
+
+    fn into_inner(self) -> Self::Backing {
+        self.stack_list
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (L, R)>
+    where
+        L: 'a,
+        R: 'a,
+    {
+        self.stack_list.iter()
+    }
+
+    fn len(&self) -> usize {
+        self.stack_list.len()
+    }
+}
+
+impl<L: Eq, R: Eq, const CAPACITY: usize> BiMapIndexMut<L, R> for BiStackMap<L, R, CAPACITY> {
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> &mut (L, R) {
+        //SAFETY: upheld by caller
+        unsafe { self.stack_list.get_unchecked_mut(index) }
+    }
+
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (L, R) {
+        //SAFETY: upheld by caller
+        unsafe { self.stack_list.remove(index).unwrap_unchecked() }
+    }
+
+    fn push_unchecked(
+        &mut self,
+        left: L,
+        right: R,
+    ) -> Result<(), <Self as FallibleLinearBiMap<L, R>>::InsertionError> {
+        self.stack_list.push((left, right))
+    }
+}
+
+impl<L: Eq, R: Eq, const CAPACITY: usize> TryFromIterator<(L, R)> for BiStackMap<L, R, CAPACITY> {
+    type Error = TryExtendError<PushError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (L, R)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<L: Eq + Clone, R: Eq + Clone, const CAPACITY: usize> TryClone for BiStackMap<L, R, CAPACITY> {
+    ///Never fails, as `BiStackMap` does not allocate.
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(self.clone())
+    }
+}
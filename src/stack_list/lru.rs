@@ -0,0 +1,156 @@
+use std::{borrow::Borrow, collections::TryReserveError};
+
+use crate::{FallibleLinearMap, LinearMapView, MapIndex, MapIndexMut, StackMap, TryClone};
+
+///A least recently used cache backed by a `StackMap`, holding at most `CAPACITY` pairs.
+///Never allocates: every pair lives on the stack.
+///
+///Pairs are kept in order of use: the least recently used pair is at the front and is the first to be evicted.
+///Promoting or evicting a pair shifts the pairs behind it, so this is best suited to small capacities.
+#[derive(Debug, Clone)]
+pub struct StackLru<K: Eq, V, const CAPACITY: usize> {
+    map: StackMap<K, V, CAPACITY>,
+}
+
+impl<K: Eq, V, const CAPACITY: usize> StackLru<K, V, CAPACITY> {
+    ///Creates a new, empty StackLru.
+    pub fn new() -> Self {
+        Self {
+            map: StackMap::new(),
+        }
+    }
+
+    ///Returns the maximum number of pairs this cache can hold.
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    ///Returns the number of pairs in this cache.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    ///Returns true if this cache holds no pairs.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    ///Appends the pair as the most recently used, returning its index.
+    ///SAFETY: UB if the cache is full.
+    unsafe fn push(&mut self, key: K, value: V) -> usize {
+        //SAFETY: upheld by caller, so the list has room for the pair.
+        unsafe { self.map.push_unchecked(key, value).unwrap_unchecked() }
+    }
+
+    ///Marks the pair at `index` as the most recently used, returning its new index.
+    ///SAFETY: UB if `index` is >= the length of the cache.
+    unsafe fn promote(&mut self, index: usize) -> usize {
+        //SAFETY: upheld by caller, and the pair which was just removed leaves room to push it back.
+        unsafe {
+            let (key, value) = self.map.remove_index_unchecked(index);
+            self.push(key, value)
+        }
+    }
+
+    ///Gets a reference to the value associated with the key, marking it as the most recently used.
+    ///Will return None if that key is not in the cache.
+    pub fn get<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        let index = unsafe { self.promote(self.map.index_of(key)?) };
+        //SAFETY: `promote` returns the new index of the pair.
+        Some(unsafe { self.map.get_index_unchecked(index) }.1)
+    }
+
+    ///Gets a mutable reference to the value associated with the key, marking it as the most recently used.
+    ///Will return None if that key is not in the cache.
+    pub fn get_mut<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        let index = unsafe { self.promote(self.map.index_of(key)?) };
+        //SAFETY: `promote` returns the new index of the pair.
+        Some(unsafe { self.map.get_index_unchecked_mut(index) }.1)
+    }
+
+    ///Gets a reference to the value associated with the key without changing the order of use.
+    ///Will return None if that key is not in the cache.
+    pub fn peek<Q: ?Sized + Eq>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.map.get(key)
+    }
+
+    ///Returns true if this cache contains the given key, without changing the order of use. False otherwise.
+    pub fn contains_key<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.map.contains_key(key)
+    }
+
+    ///Inserts the pair into the cache as the most recently used.
+    ///Returns the pair it displaced: the previous pair with the same key if there was one, else the least recently used pair
+    ///if the cache was full. A cache with a capacity of zero hands the provided pair straight back.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        let displaced = match self.map.index_of(&key) {
+            //SAFETY: `index_of` only returns indices of pairs within the map.
+            Some(index) => Some(unsafe { self.map.remove_index_unchecked(index) }),
+            None => match self.len() < CAPACITY {
+                true => None,
+                false => match self.pop_lru() {
+                    Some(evicted) => Some(evicted),
+                    None => return Some((key, value)),
+                },
+            },
+        };
+
+        //SAFETY: either the cache was not full, or a pair was just removed to make room.
+        unsafe { self.push(key, value) };
+        displaced
+    }
+
+    ///Tries to remove the value associated with the given key, returning None if it is not found.
+    pub fn remove<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.map.remove(key)
+    }
+
+    ///Removes and returns the least recently used pair, or None if the cache is empty.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        match self.is_empty() {
+            true => None,
+            //SAFETY: the cache is not empty.
+            false => Some(unsafe { self.map.remove_index_unchecked(0) }),
+        }
+    }
+
+    ///Removes every pair from the cache.
+    pub fn clear(&mut self) {
+        self.map.drain().for_each(drop)
+    }
+
+    ///Iterates over the pairs in this cache from the least to the most recently used.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.map.iter()
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> Default for StackLru<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Clone, V: Clone, const CAPACITY: usize> TryClone for StackLru<K, V, CAPACITY> {
+    ///Never fails, as `StackLru` does not allocate.
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(self.clone())
+    }
+}
@@ -1,10 +1,15 @@
+use std::{borrow::Borrow, collections::TryReserveError};
+
 use crate::{
-    FallibleLinearMap,
+    policy::Reorder,
+    search::KeySearch,
     stack_list::{error::PushError, StackList},
-    MapIterMut,
+    FallibleLinearMap, LinearMapValuesMut, LinearMapView, MapExtract, MapIndex, MapIndexMut,
+    MapIterMut, PositionalLinearMap, TryClone, TryExtendError, TryFromIterator,
 };
 
 ///A map backed by a `StackList`
+#[derive(Clone)]
 pub struct StackMap<K: Eq, V, const CAPACITY: usize> {
     stack_list: StackList<(K, V), CAPACITY>,
 }
@@ -22,12 +27,13 @@ impl<K: Eq, V, const CAPACITY: usize> FallibleLinearMap<K, V> for StackMap<K, V,
     type InsertionError = PushError;
 
     fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError> {
-        let mut iter = self.stack_list.iter_mut();
-        match iter.find(|(k, _)| *k == key) {
-            Some((_, v)) => Ok(Some(std::mem::replace(v, value))),
+        match self.index_of(&key) {
+            //SAFETY: `index_of` only returns indices of pairs within the map.
+            Some(index) => Ok(Some(std::mem::replace(
+                &mut unsafe { self.stack_list.get_unchecked_mut(index) }.1,
+                value,
+            ))),
             None => {
-                //need to manually drop because the Result gets created as a temporary (?)
-                drop(iter);
                 self.stack_list.push((key, value))?;
                 Ok(None)
             }
@@ -37,40 +43,160 @@ impl<K: Eq, V, const CAPACITY: usize> FallibleLinearMap<K, V> for StackMap<K, V,
         self.stack_list
     }
 
-    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (K, V)>
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
     where
-        K: 'a,
-        V: 'a,
+        K: Borrow<Q>,
     {
-        self.stack_list.iter()
-    }
-
-    fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
-        let idx = self
-            .stack_list
-            .iter()
-            .enumerate()
-            .find(|(_, (k, _))| k == key)
-            .map(|(i, _)| i)?;
+        let idx = self.index_of(key)?;
 
         self.stack_list.remove(idx)
     }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> LinearMapView<K, V> for StackMap<K, V, CAPACITY> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.stack_list.iter().map(|(k, v)| (k, v))
+    }
 
     fn len(&self) -> usize {
         self.stack_list.len()
     }
 }
 
+impl<K: Eq, V, const CAPACITY: usize> LinearMapValuesMut<K, V> for StackMap<K, V, CAPACITY> {}
+
 impl<K: Eq, V, const STACK_CAPACITY: usize> MapIterMut<K, V> for StackMap<K, V, STACK_CAPACITY> {
-    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut (K, V)>
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.stack_list.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Eq, V, const STACK_CAPACITY: usize> MapExtract<K, V> for StackMap<K, V, STACK_CAPACITY> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
     where
         K: 'a,
         V: 'a,
     {
-        self.stack_list.iter_mut()
+        self.stack_list.extract_if(move |(k, v)| pred(k, v))
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> MapIndexMut<K, V> for StackMap<K, V, CAPACITY> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //SAFETY: upheld by caller
+        unsafe { self.stack_list.remove(index).unwrap_unchecked() }
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.stack_list.push((key, value))?;
+        Ok(self.stack_list.len() - 1)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        match CAPACITY - self.stack_list.len() >= additional {
+            true => Ok(()),
+            false => Err(PushError::WouldExceedCapacity),
+        }
+    }
+
+    fn reserve_hint(
+        &mut self,
+        _additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        //an iterator may yield keys which are already present, so its size hint can't tell us it won't fit.
+        Ok(())
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> MapIndex<K, V> for StackMap<K, V, CAPACITY> {
+    fn index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        K::position(self.stack_list.as_slice(), key)
+    }
+
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.stack_list.get_unchecked(index) };
+        (k, v)
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.stack_list.get_unchecked_mut(index) };
+        (k, v)
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> Reorder for StackMap<K, V, CAPACITY> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        //SAFETY: upheld by caller
+        unsafe { Reorder::swap_unchecked(&mut self.stack_list, a, b) }
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //SAFETY: upheld by caller
+        unsafe { self.stack_list.move_to_front_unchecked(index) }
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> PositionalLinearMap<K, V> for StackMap<K, V, CAPACITY> {}
+
+impl<K: Eq, V, const CAPACITY: usize> Default for StackMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> TryFromIterator<(K, V)> for StackMap<K, V, CAPACITY> {
+    type Error = TryExtendError<PushError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Eq + Clone, V: Clone, const CAPACITY: usize> TryClone for StackMap<K, V, CAPACITY> {
+    ///Never fails, as `StackMap` does not allocate.
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(self.clone())
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> IntoIterator for StackMap<K, V, CAPACITY> {
+    type Item = (K, V);
+
+    type IntoIter = super::StackListIterator<(K, V), CAPACITY>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.stack_list.into_iter()
     }
 }
 
+crate::std_traits::map_traits!([K: Eq, V, const CAPACITY: usize] StackMap<K, V, CAPACITY>);
+crate::std_traits::map_index!([K: Eq, V, const CAPACITY: usize] StackMap<K, V, CAPACITY>);
+
 #[cfg(feature = "serde")]
 impl<'a, K: Eq + serde::Serialize, V: PartialEq + serde::Serialize, const CAPACITY: usize>
     serde::Serialize for StackMap<K, V, CAPACITY>
@@ -82,3 +208,15 @@ impl<'a, K: Eq + serde::Serialize, V: PartialEq + serde::Serialize, const CAPACI
         crate::serde::serialize_fallible_map(self, serializer)
     }
 }
+
+#[cfg(feature = "serde")]
+impl<'de, K: Eq + serde::Deserialize<'de>, V: serde::Deserialize<'de>, const CAPACITY: usize>
+    serde::Deserialize<'de> for StackMap<K, V, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
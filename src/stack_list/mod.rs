@@ -1,13 +1,24 @@
 use std::{
-    array, hash::Hash, mem::MaybeUninit, ptr::{addr_of, addr_of_mut, copy}
+    array, collections::TryReserveError, hash::Hash, mem::MaybeUninit, ptr::{addr_of, addr_of_mut, copy}
 };
 
 #[cfg(feature = "serde")]
 mod serde;
 pub mod set;
+pub mod bimap;
+pub mod lru;
 pub mod map;
+pub mod soa_map;
+pub mod sorted_map;
 use error::PushError;
 
+use crate::{
+    extend::{try_extend_with, TryExtendError},
+    policy::Reorder,
+    soa::Column,
+    TryClone, TryFromIterator,
+};
+
 pub mod error;
 #[cfg(test)]
 mod test;
@@ -64,7 +75,8 @@ impl<T, const CAPACITY: usize> RawStackList<T, CAPACITY> {
             .for_each(|t| unsafe { t.assume_init_drop() });
     }
 
-    ///TODO: figure this out in the face of tryclone. What does the sig look like.
+    ///SAFETY: UB if `limit` is beyond CAPACITY *OR* any element before it is uninitialized.
+    ///Clones all elements up to `limit`, exclusive, into a new `RawStackList`.
     pub unsafe fn clone_to(&self, limit: usize) -> Self
     where
         T: Clone,
@@ -137,6 +149,28 @@ impl<T, const CAPACITY: usize> RawStackList<T, CAPACITY> {
         t
     }
 
+    ///Returns a raw pointer to the first slot of the array. Slots may or may not be initialized.
+    pub fn as_ptr(&self) -> *const T {
+        self.array.as_ptr().cast()
+    }
+
+    ///Returns a raw pointer to the first slot of the array. Slots may or may not be initialized.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.array.as_mut_ptr().cast()
+    }
+
+    ///Shifts the elements from `index` up to `length` right by one, then writes `value` to `index`.
+    ///SAFETY: UB if `index` > `length`, `length` >= CAPACITY, or any element before `length` is uninitialized.
+    pub unsafe fn insert_shift(&mut self, index: usize, length: usize, value: T) {
+        let base = self.as_mut_ptr();
+
+        //SAFETY: upheld by caller. `copy` handles the overlap.
+        unsafe {
+            copy(base.add(index), base.add(index + 1), length - index);
+            base.add(index).write(value);
+        }
+    }
+
     ///SAFETY: UB if index >= CAPACITY.
     pub unsafe fn insert_at(&mut self, index: usize, value: T) {
         //SAFETY: addressed by the disclosure on the function signature
@@ -236,6 +270,31 @@ impl<T, const CAPACITY: usize> StackList<T, CAPACITY> {
         }
     }
 
+    ///Appends every item of `iter` to this `StackList`.
+    ///On failure, the items pushed before the error remain in the `StackList`.
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryExtendError<PushError>> {
+        try_extend_with(iter.into_iter(), |t| self.push(t))
+    }
+
+    ///Inserts `value` at `index`, shifting every item after it right by one.
+    ///SAFETY:
+    ///UB if `index` is > the length of this `StackList`.
+    pub(crate) unsafe fn insert_unchecked(&mut self, index: usize, value: T) -> Result<(), PushError> {
+        match self.length < CAPACITY {
+            true => {
+                //SAFETY: `index` is upheld by caller, and we just checked there is room to shift into.
+                unsafe { self.raw.insert_shift(index, self.length, value) };
+                self.length += 1;
+
+                Ok(())
+            }
+            false => Err(PushError::WouldExceedCapacity),
+        }
+    }
+
     pub fn remove(&mut self, index: usize) -> Option<T> {
         match self.raw.is_within_capacity(index) && self.length > 0 {
             //SAFETY: we track len and know it is not > CAPACITY in this arm
@@ -268,6 +327,240 @@ impl<T, const CAPACITY: usize> StackList<T, CAPACITY> {
             false => None,
         }
     }
+
+    ///Removes and yields every item for which `pred` returns true, shifting the kept items left in a single pass.
+    ///Items which have not been visited when the iterator is dropped are kept.
+    pub(crate) fn extract_if<F: FnMut(&mut T) -> bool>(
+        &mut self,
+        pred: F,
+    ) -> ExtractIf<'_, T, CAPACITY, F> {
+        let original_len = self.length;
+
+        //If the iterator is leaked the items are leaked with it, rather than being dropped twice.
+        self.length = 0;
+
+        ExtractIf {
+            list: self,
+            pred,
+            read: 0,
+            write: 0,
+            original_len,
+        }
+    }
+
+    ///Returns the initialized elements of this `StackList` as a slice.
+    pub(crate) fn as_slice(&self) -> &[T] {
+        //SAFETY: every slot below `length` is initialized.
+        unsafe { std::slice::from_raw_parts(self.raw.as_ptr(), self.length) }
+    }
+
+    ///Returns a shared reference to the item at `index`.
+    ///SAFETY:
+    ///UB if `index` is >= the length of this `StackList`.
+    pub(crate) unsafe fn get_unchecked(&self, index: usize) -> &T {
+        //SAFETY: upheld by caller. Length never exceeds CAPACITY.
+        unsafe { self.raw.get(index) }
+    }
+
+    ///Returns a unique reference to the item at `index`.
+    ///SAFETY:
+    ///UB if `index` is >= the length of this `StackList`.
+    pub(crate) unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        //SAFETY: upheld by caller. Length never exceeds CAPACITY.
+        unsafe { self.raw.get_mut(index) }
+    }
+}
+
+impl<T, const CAPACITY: usize> Reorder for StackList<T, CAPACITY> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        let base = self.raw.as_mut_ptr();
+        //SAFETY: both indices are below the length as upheld by the caller, so both slots are initialized.
+        unsafe { std::ptr::swap(base.add(a), base.add(b)) }
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //SAFETY: `index` is below the length as upheld by the caller, so every slot up to it is initialized.
+        unsafe { std::slice::from_raw_parts_mut(self.raw.as_mut_ptr(), index + 1) }.rotate_right(1)
+    }
+}
+
+impl<T, const CAPACITY: usize> Column for StackList<T, CAPACITY> {
+    type Item = T;
+    type Slots = *mut T;
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    unsafe fn set_len(&mut self, len: usize) {
+        self.length = len;
+    }
+
+    fn slots(&mut self) -> *mut T {
+        self.raw.as_mut_ptr()
+    }
+
+    unsafe fn slot(slots: *mut T, index: usize) -> *mut T {
+        //SAFETY: upheld by caller
+        unsafe { slots.add(index) }
+    }
+}
+
+impl<T: Clone, const CAPACITY: usize> Clone for StackList<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        Self {
+            //SAFETY: bound by length so will not go out of bounds or into uninit memory
+            raw: unsafe { self.raw.clone_to(self.length) },
+            length: self.length,
+        }
+    }
+}
+
+impl<T: Clone, const CAPACITY: usize> TryClone for StackList<T, CAPACITY> {
+    ///Never fails, as `StackList` does not allocate.
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(self.clone())
+    }
+}
+
+impl<T, const CAPACITY: usize> TryFromIterator<T> for StackList<T, CAPACITY> {
+    type Error = TryExtendError<PushError>;
+
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        let mut list = Self::new();
+        list.try_extend(iter)?;
+        Ok(list)
+    }
+}
+
+///Iterator returned by `StackList::extract_if`.
+pub(crate) struct ExtractIf<'a, T, const CAPACITY: usize, F: FnMut(&mut T) -> bool> {
+    list: &'a mut StackList<T, CAPACITY>,
+    pred: F,
+    ///Index of the next item to test.
+    read: usize,
+    ///Number of items kept so far. These have all been shifted to the front of the list.
+    write: usize,
+    ///The length of the list before extraction began.
+    original_len: usize,
+}
+
+impl<'a, T, const CAPACITY: usize, F: FnMut(&mut T) -> bool> Iterator
+    for ExtractIf<'a, T, CAPACITY, F>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.read < self.original_len {
+            let base = self.list.raw.as_mut_ptr();
+
+            //SAFETY: everything in `read..original_len` is initialized and untouched.
+            let current = unsafe { base.add(self.read) };
+            let extract = (self.pred)(unsafe { &mut *current });
+
+            self.read += 1;
+
+            match extract {
+                //SAFETY: `read` has moved past this slot, so it will never be read again.
+                true => return Some(unsafe { current.read() }),
+                false => {
+                    //SAFETY: `write` < `read`, so the slot at `write` has either been moved out of or is this slot.
+                    unsafe { copy(current, base.add(self.write), 1) };
+                    self.write += 1;
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.original_len - self.read))
+    }
+}
+
+impl<'a, T, const CAPACITY: usize, F: FnMut(&mut T) -> bool> Drop
+    for ExtractIf<'a, T, CAPACITY, F>
+{
+    fn drop(&mut self) {
+        let tail = self.original_len - self.read;
+
+        //SAFETY: shifts the unvisited items left to sit directly after the kept items.
+        //`copy` handles the overlap.
+        unsafe {
+            let base = self.list.raw.as_mut_ptr();
+            copy(base.add(self.read), base.add(self.write), tail);
+        }
+
+        self.list.length = self.write + tail;
+    }
+}
+
+///Owning iterator over the items of a `StackList`, from front to back.
+///Items which have not been yielded when the iterator is dropped are dropped with it.
+pub struct StackListIterator<T, const CAPACITY: usize> {
+    list: StackList<T, CAPACITY>,
+    ///Index of the next item to yield. Every slot from here up to the list's length is initialized.
+    front: usize,
+}
+
+impl<T, const CAPACITY: usize> Iterator for StackListIterator<T, CAPACITY> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self.front < self.list.length {
+            true => {
+                //SAFETY: `front` is below the length so the slot is initialized,
+                //and moving past it means it will never be read again.
+                let t = unsafe { self.list.raw.as_ptr().add(self.front).read() };
+                self.front += 1;
+                Some(t)
+            }
+            false => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.length - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T, const CAPACITY: usize> DoubleEndedIterator for StackListIterator<T, CAPACITY> {
+    fn next_back(&mut self) -> Option<T> {
+        match self.front < self.list.length {
+            true => {
+                self.list.length -= 1;
+                //SAFETY: the slot at the old last index is initialized, and is now past the length.
+                Some(unsafe { self.list.raw.as_ptr().add(self.list.length).read() })
+            }
+            false => None,
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> ExactSizeIterator for StackListIterator<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> Drop for StackListIterator<T, CAPACITY> {
+    fn drop(&mut self) {
+        let base = self.list.raw.as_mut_ptr();
+        //SAFETY: every slot in `front..length` is initialized and has not been yielded.
+        (self.front..self.list.length).for_each(|i| unsafe { base.add(i).drop_in_place() });
+        self.list.length = 0;
+    }
+}
+
+impl<T, const CAPACITY: usize> IntoIterator for StackList<T, CAPACITY> {
+    type Item = T;
+
+    type IntoIter = StackListIterator<T, CAPACITY>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        StackListIterator {
+            list: self,
+            front: 0,
+        }
+    }
 }
 
 impl<const CAPACITY: usize, T: PartialEq> PartialEq for StackList<T, CAPACITY> {
@@ -1,7 +1,13 @@
-use crate::{FallibleLinearMap, FallibleLinearSet};
+use std::{borrow::Borrow, collections::TryReserveError};
+
+use crate::{
+    stack_list::error::PushError, FallibleLinearMap, FallibleLinearSet, LinearMapView, TryClone,
+    TryExtendError, TryFromIterator,
+};
 
 use super::map::StackMap;
 
+#[derive(Clone)]
 pub struct StackSet<T: Eq, const CAPACITY: usize> {
     map: StackMap<T, (), CAPACITY>,
 }
@@ -25,15 +31,17 @@ impl<T: Eq, const STACK_CAPACITY: usize> FallibleLinearSet<T> for StackSet<T, ST
         &mut self.map
     }
 
-    fn contains(&self, value: &T) -> bool {
+    fn contains<Q: ?Sized + Eq>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
         self.map().contains_key(value)
     }
 
     fn insert(
         &mut self,
         value: T,
-    ) -> Result<bool, <Self::Backing as crate::FallibleLinearMap<T, ()>>::InsertionError>
-    {
+    ) -> Result<bool, <Self::Backing as crate::FallibleLinearMap<T, ()>>::InsertionError> {
         self.map_mut().insert(value, ()).map(|r| r.is_none())
     }
 
@@ -52,11 +60,39 @@ impl<T: Eq, const STACK_CAPACITY: usize> FallibleLinearSet<T> for StackSet<T, ST
         self.map().keys()
     }
 
-    fn remove(&mut self, value: &T) -> Option<T> {
+    fn remove<Q: ?Sized + Eq>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
         self.map_mut().remove_entry(value).map(|(k, _)| k)
     }
 }
 
+impl<T: Eq, const CAPACITY: usize> Default for StackSet<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> TryFromIterator<T> for StackSet<T, CAPACITY> {
+    type Error = TryExtendError<PushError>;
+
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        let mut set = Self::new();
+        set.try_extend(iter)?;
+        Ok(set)
+    }
+}
+
+impl<T: Eq + Clone, const CAPACITY: usize> TryClone for StackSet<T, CAPACITY> {
+    ///Never fails, as `StackSet` does not allocate.
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(self.clone())
+    }
+}
+
+crate::std_traits::set_traits!([T: Eq, const STACK_CAPACITY: usize] StackSet<T, STACK_CAPACITY>);
+
 #[cfg(feature = "serde")]
 impl<'a, T: Eq + serde::Serialize, const CAPACITY: usize> serde::Serialize
     for StackSet<T, CAPACITY>
@@ -68,3 +104,15 @@ impl<'a, T: Eq + serde::Serialize, const CAPACITY: usize> serde::Serialize
         crate::serde::serialize_fallible_set(self, serializer)
     }
 }
+
+#[cfg(feature = "serde")]
+impl<'de, T: Eq + serde::Deserialize<'de>, const CAPACITY: usize> serde::Deserialize<'de>
+    for StackSet<T, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_set(deserializer)
+    }
+}
@@ -0,0 +1,259 @@
+use std::{borrow::Borrow, collections::TryReserveError};
+
+use crate::{
+    policy::Reorder,
+    search::KeySearch,
+    soa::ExtractPairs,
+    stack_list::{error::PushError, StackList},
+    FallibleLinearMap, LinearMapValuesMut, LinearMapView, MapExtract, MapIndex, MapIndexMut,
+    MapIterMut, PositionalLinearMap, TryClone, TryExtendError, TryFromIterator,
+};
+
+///A map backed by two `StackList`s: one holding the keys and the other their values, in the same order.
+///Searching the map only touches the keys, so lookups stay cache friendly when the values are large.
+#[derive(Clone)]
+pub struct SoaStackMap<K: Eq, V, const CAPACITY: usize> {
+    keys: StackList<K, CAPACITY>,
+    values: StackList<V, CAPACITY>,
+}
+
+impl<K: Eq, V, const CAPACITY: usize> SoaStackMap<K, V, CAPACITY> {
+    pub fn new() -> Self {
+        Self {
+            keys: StackList::new(),
+            values: StackList::new(),
+        }
+    }
+
+    ///Returns the keys of this map, in the same order as its values.
+    pub fn keys_slice(&self) -> &[K] {
+        self.keys.as_slice()
+    }
+
+    ///Returns the values of this map, in the same order as its keys.
+    pub fn values_slice(&self) -> &[V] {
+        self.values.as_slice()
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> Default for SoaStackMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> FallibleLinearMap<K, V> for SoaStackMap<K, V, CAPACITY> {
+    type Backing = (StackList<K, CAPACITY>, StackList<V, CAPACITY>);
+    type InsertionError = PushError;
+
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError> {
+        match self.index_of(&key) {
+            //SAFETY: `index_of` only returns indices of pairs within the map.
+            Some(index) => Ok(Some(std::mem::replace(
+                unsafe { self.values.get_unchecked_mut(index) },
+                value,
+            ))),
+            None => self.push_unchecked(key, value).map(|_| None),
+        }
+    }
+
+    fn into_inner(self) -> Self::Backing {
+        (self.keys, self.values)
+    }
+
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.index_of(key)?;
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        Some(unsafe { self.remove_index_unchecked(index) })
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> LinearMapView<K, V> for SoaStackMap<K, V, CAPACITY> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.keys.iter().zip(self.values.iter())
+    }
+
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> LinearMapValuesMut<K, V> for SoaStackMap<K, V, CAPACITY> {}
+
+impl<K: Eq, V, const CAPACITY: usize> MapIterMut<K, V> for SoaStackMap<K, V, CAPACITY> {
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.keys.iter().zip(self.values.iter_mut())
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> MapExtract<K, V> for SoaStackMap<K, V, CAPACITY> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        //SAFETY: there is a value for every key.
+        unsafe { ExtractPairs::new(&mut self.keys, &mut self.values, pred) }
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> MapIndexMut<K, V> for SoaStackMap<K, V, CAPACITY> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //SAFETY: upheld by caller
+        unsafe {
+            (
+                self.keys.remove(index).unwrap_unchecked(),
+                self.values.remove(index).unwrap_unchecked(),
+            )
+        }
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.keys.push(key)?;
+        //SAFETY: both lists share a capacity and a length, so there is room for the value if there was for the key.
+        unsafe { self.values.push(value).unwrap_unchecked() };
+        Ok(self.keys.len() - 1)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        match CAPACITY - self.keys.len() >= additional {
+            true => Ok(()),
+            false => Err(PushError::WouldExceedCapacity),
+        }
+    }
+
+    fn reserve_hint(
+        &mut self,
+        _additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        //an iterator may yield keys which are already present, so its size hint can't tell us it won't fit.
+        Ok(())
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> MapIndex<K, V> for SoaStackMap<K, V, CAPACITY> {
+    fn index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        K::position_in_keys(self.keys.as_slice(), key)
+    }
+
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        unsafe {
+            (
+                self.keys.get_unchecked(index),
+                self.values.get_unchecked(index),
+            )
+        }
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        unsafe {
+            (
+                self.keys.get_unchecked(index),
+                self.values.get_unchecked_mut(index),
+            )
+        }
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> Reorder for SoaStackMap<K, V, CAPACITY> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        //SAFETY: upheld by caller
+        unsafe {
+            Reorder::swap_unchecked(&mut self.keys, a, b);
+            Reorder::swap_unchecked(&mut self.values, a, b);
+        }
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //SAFETY: upheld by caller
+        unsafe {
+            self.keys.move_to_front_unchecked(index);
+            self.values.move_to_front_unchecked(index);
+        }
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> PositionalLinearMap<K, V> for SoaStackMap<K, V, CAPACITY> {}
+
+impl<K: Eq, V, const CAPACITY: usize> TryFromIterator<(K, V)> for SoaStackMap<K, V, CAPACITY> {
+    type Error = TryExtendError<PushError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Eq + Clone, V: Clone, const CAPACITY: usize> TryClone for SoaStackMap<K, V, CAPACITY> {
+    ///Never fails, as `SoaStackMap` does not allocate.
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(self.clone())
+    }
+}
+
+impl<K: Eq, V, const CAPACITY: usize> IntoIterator for SoaStackMap<K, V, CAPACITY> {
+    type Item = (K, V);
+
+    type IntoIter = std::iter::Zip<
+        super::StackListIterator<K, CAPACITY>,
+        super::StackListIterator<V, CAPACITY>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter().zip(self.values)
+    }
+}
+
+crate::std_traits::map_traits!([K: Eq, V, const CAPACITY: usize] SoaStackMap<K, V, CAPACITY>);
+crate::std_traits::map_index!([K: Eq, V, const CAPACITY: usize] SoaStackMap<K, V, CAPACITY>);
+
+#[cfg(feature = "serde")]
+impl<K: Eq + serde::Serialize, V: PartialEq + serde::Serialize, const CAPACITY: usize>
+    serde::Serialize for SoaStackMap<K, V, CAPACITY>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serde::serialize_fallible_map(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K: Eq + serde::Deserialize<'de>, V: serde::Deserialize<'de>, const CAPACITY: usize>
+    serde::Deserialize<'de> for SoaStackMap<K, V, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
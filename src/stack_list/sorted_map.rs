@@ -0,0 +1,302 @@
+use std::{borrow::Borrow, collections::TryReserveError};
+
+use crate::{
+    sorted::search,
+    stack_list::{error::PushError, StackList},
+    Entry, FallibleLinearMap, LinearMapValuesMut, LinearMapView, MapExtract, MapIndex, MapIndexMut,
+    MapIterMut, OccupiedEntry, SortedLinearMap, TryClone, TryExtendError, TryFromIterator,
+    VacantEntry,
+};
+
+///A map backed by a `StackList` which keeps its pairs sorted by key, so that lookups are O(log n) binary searches.
+///Never allocates: insertions return an error once `CAPACITY` pairs are held.
+pub struct SortedStackMap<K: Ord, V, const CAPACITY: usize> {
+    stack_list: StackList<(K, V), CAPACITY>,
+}
+
+impl<K: Ord, V, const CAPACITY: usize> SortedStackMap<K, V, CAPACITY> {
+    ///Creates a new, empty SortedStackMap.
+    pub fn new() -> Self {
+        SortedStackMap {
+            stack_list: StackList::new(),
+        }
+    }
+
+    ///Gets a reference to the value associated with the key, found by binary search.
+    ///Will return None if that key is not in the map.
+    pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = search(self, key).ok()?;
+        //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+        Some(unsafe { self.get_index_unchecked(index) }.1)
+    }
+
+    ///Gets a mutable reference to the value associated with the key, found by binary search.
+    ///Will return None if that key is not in the map.
+    pub fn get_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = search(self, key).ok()?;
+        //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+        Some(unsafe { self.get_index_unchecked_mut(index) }.1)
+    }
+
+    ///Returns true if this map contains the given key, found by binary search. False otherwise.
+    pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        search(self, key).is_ok()
+    }
+
+    ///Tries to remove the entry associated with the given key, found by binary search, returning None if it is not found.
+    pub fn remove_entry<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let index = search(self, key).ok()?;
+        self.stack_list.remove(index)
+    }
+
+    ///Tries to remove the value associated with the given key, found by binary search, returning None if it is not found.
+    pub fn remove<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    ///Gets the given key's corresponding entry in the map for in-place manipulation, found by binary search.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, Self> {
+        match search(self, &key) {
+            //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+            Ok(index) => Entry::Occupied(unsafe { OccupiedEntry::new(self, index) }),
+            Err(_) => Entry::Vacant(VacantEntry::new(self, key)),
+        }
+    }
+
+    ///Inserts the pair at `index`, which must be where `key` belongs in the sorted order.
+    fn insert_at(&mut self, index: usize, key: K, value: V) -> Result<(), PushError> {
+        //SAFETY: indices found by `search` are at most the length of the map.
+        unsafe { self.stack_list.insert_unchecked(index, (key, value)) }
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> Default for SortedStackMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> FallibleLinearMap<K, V> for SortedStackMap<K, V, CAPACITY> {
+    type Backing = StackList<(K, V), CAPACITY>;
+    type InsertionError = PushError;
+
+    fn into_inner(self) -> Self::Backing {
+        self.stack_list
+    }
+
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let idx = self
+            .stack_list
+            .iter()
+            .position(|(k, _)| k.borrow() == key)?;
+
+        self.stack_list.remove(idx)
+    }
+
+    ///Gets the given key's corresponding entry in the map, found by binary search like the inherent `entry`.
+    fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V, Self>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        SortedStackMap::entry(self, key)
+    }
+
+    ///Inserts the provided pair where its key belongs in the sorted order. If the provided key is
+    ///found it will update the value and return the old value. If not, this may allocate for a new key value pair.
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError> {
+        match search(self, &key) {
+            //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+            Ok(index) => Ok(Some(std::mem::replace(
+                unsafe { self.get_index_unchecked_mut(index) }.1,
+                value,
+            ))),
+            Err(index) => self.insert_at(index, key, value).map(|_| None),
+        }
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> LinearMapView<K, V> for SortedStackMap<K, V, CAPACITY> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.stack_list.iter().map(|(k, v)| (k, v))
+    }
+
+    fn len(&self) -> usize {
+        self.stack_list.len()
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> LinearMapValuesMut<K, V> for SortedStackMap<K, V, CAPACITY> {}
+
+impl<K: Ord, V, const CAPACITY: usize> SortedLinearMap<K, V> for SortedStackMap<K, V, CAPACITY> {}
+
+impl<K: Ord, V, const CAPACITY: usize> MapIterMut<K, V> for SortedStackMap<K, V, CAPACITY> {
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.stack_list.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> MapExtract<K, V> for SortedStackMap<K, V, CAPACITY> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.stack_list.extract_if(move |(k, v)| pred(k, v))
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> MapIndexMut<K, V> for SortedStackMap<K, V, CAPACITY> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //SAFETY: in bounds as upheld by the caller, so this is always `Some`.
+        unsafe { self.stack_list.remove(index).unwrap_unchecked() }
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        let index = search(self, &key).unwrap_or_else(|index| index);
+        self.insert_at(index, key, value)?;
+        Ok(index)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        match CAPACITY - self.stack_list.len() >= additional {
+            true => Ok(()),
+            false => Err(PushError::WouldExceedCapacity),
+        }
+    }
+
+    fn reserve_hint(
+        &mut self,
+        _additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        //an iterator may yield keys which are already present, so its size hint can't tell us it won't fit.
+        Ok(())
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> MapIndex<K, V> for SortedStackMap<K, V, CAPACITY> {
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.stack_list.get_unchecked(index) };
+        (k, v)
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.stack_list.get_unchecked_mut(index) };
+        (k, v)
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> TryFromIterator<(K, V)> for SortedStackMap<K, V, CAPACITY> {
+    type Error = TryExtendError<PushError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Ord + Clone, V: Clone, const CAPACITY: usize> Clone for SortedStackMap<K, V, CAPACITY> {
+    fn clone(&self) -> Self {
+        SortedStackMap {
+            stack_list: self.stack_list.clone(),
+        }
+    }
+}
+
+impl<K: Ord + Clone, V: Clone, const CAPACITY: usize> TryClone for SortedStackMap<K, V, CAPACITY> {
+    ///Never fails, as `SortedStackMap` does not allocate.
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(self.clone())
+    }
+}
+
+impl<K: Ord, V, const CAPACITY: usize> IntoIterator for SortedStackMap<K, V, CAPACITY> {
+    type Item = (K, V);
+
+    type IntoIter = super::StackListIterator<(K, V), CAPACITY>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.stack_list.into_iter()
+    }
+}
+
+crate::std_traits::map_traits!([K: Ord, V, const CAPACITY: usize] SortedStackMap<K, V, CAPACITY>);
+
+impl<K: Ord, V, const CAPACITY: usize, Q: ?Sized + Ord> std::ops::Index<&Q>
+    for SortedStackMap<K, V, CAPACITY>
+where
+    K: Borrow<Q>,
+{
+    type Output = V;
+
+    ///Returns a reference to the value corresponding to `key`, found by binary search like `get`.
+    ///
+    ///# Panics
+    ///Panics if `key` is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in map")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K: Ord + serde::Serialize, V: PartialEq + serde::Serialize, const CAPACITY: usize>
+    serde::Serialize for SortedStackMap<K, V, CAPACITY>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serde::serialize_fallible_map(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K: Ord + serde::Deserialize<'de>, V: serde::Deserialize<'de>, const CAPACITY: usize>
+    serde::Deserialize<'de> for SortedStackMap<K, V, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
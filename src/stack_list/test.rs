@@ -4,6 +4,7 @@ use std::{
 };
 
 use super::{RawStackList, StackList};
+use crate::TryFromIterator;
 
 ///Just a helper to test drop behavior
 struct Dropper {
@@ -346,3 +347,18 @@ pub fn stack_list_clear_to_drops() {
     assert_eq!(*fourth_was_dropped.lock().unwrap().deref(), true);
     assert_eq!(*fifth_was_dropped.lock().unwrap().deref(), true);
 }
+
+#[test]
+fn into_iter_drops_unyielded_items() {
+    let flags: Vec<_> = (0..3).map(|_| Arc::new(Mutex::new(false))).collect();
+    let list = StackList::<Dropper, 4>::try_from_iter(flags.iter().cloned().map(Dropper::new)).unwrap();
+
+    let mut iter = list.into_iter();
+    assert_eq!(iter.len(), 3);
+    drop(iter.next_back());
+    assert!(*flags[2].lock().unwrap());
+    assert!(!*flags[0].lock().unwrap());
+
+    drop(iter);
+    assert!(flags.iter().all(|f| *f.lock().unwrap()));
+}
@@ -0,0 +1,180 @@
+//!Macros implementing the std traits shared by every map and set in terms of the crate's own traits,
+//!so that `Debug`, `Index`, equality and iteration by reference behave identically whatever the backing store.
+//!
+//!Maps and sets compare like the std collections: two are equal if they hold the same pairs or values,
+//!in any order, even if their backing stores differ. Their `Hash` is likewise independent of order.
+//!
+//!Owned `IntoIterator` impls for maps are written alongside each type, as they depend on its backing store.
+//!`Extend` impls live in the panicking module, as they cannot report a failed insertion.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::LinearMapView;
+
+///Implements `Debug` shaped like `{k: v}`, `IntoIterator` for `&M` and `&mut M`, and order independent
+///`PartialEq` against every `LinearMapView`, `Eq` and `Hash`.
+///The first argument lists the generics of the impl, which must name the key and value types `K` and `V`.
+macro_rules! map_traits {
+    ([$($generics:tt)*] $map:ty) => {
+        impl<$($generics)*> std::fmt::Debug for $map
+        where
+            K: std::fmt::Debug,
+            V: std::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_map()
+                    .entries(crate::LinearMapView::iter(self))
+                    .finish()
+            }
+        }
+
+        impl<'a, $($generics)*> IntoIterator for &'a $map {
+            type Item = (&'a K, &'a V);
+            type IntoIter = impl Iterator<Item = (&'a K, &'a V)>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                crate::LinearMapView::iter(self)
+            }
+        }
+
+        impl<'a, $($generics)*> IntoIterator for &'a mut $map {
+            type Item = (&'a K, &'a mut V);
+            type IntoIter = impl Iterator<Item = (&'a K, &'a mut V)>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                crate::MapIterMut::iter_mut(self)
+            }
+        }
+
+        impl<$($generics)*, M: crate::LinearMapView<K, V> + ?Sized> PartialEq<M> for $map
+        where
+            V: PartialEq,
+        {
+            fn eq(&self, other: &M) -> bool {
+                crate::std_traits::eq_unordered(self, other)
+            }
+        }
+
+        impl<$($generics)*> Eq for $map where V: Eq {}
+
+        impl<$($generics)*> std::hash::Hash for $map
+        where
+            K: std::hash::Hash,
+            V: std::hash::Hash,
+        {
+            ///Hashes the pairs regardless of their order, see `hash_unordered`.
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                crate::std_traits::hash_unordered(crate::LinearMapView::iter(self), state)
+            }
+        }
+    };
+}
+
+///Implements `Index<&Q>` through `LinearMapView::get`, panicking if the key is not in the map.
+///Maps with faster lookups than a linear scan implement `Index` themselves.
+macro_rules! map_index {
+    ([$($generics:tt)*] $map:ty) => {
+        impl<$($generics)*, Q: ?Sized + Eq> std::ops::Index<&Q> for $map
+        where
+            K: std::borrow::Borrow<Q>,
+        {
+            type Output = V;
+
+            ///Returns a reference to the value corresponding to `key`.
+            ///
+            ///# Panics
+            ///Panics if `key` is not present in the map.
+            fn index(&self, key: &Q) -> &V {
+                crate::LinearMapView::get(self, key).expect("key not found in map")
+            }
+        }
+    };
+}
+
+///Implements `Debug` shaped like `{a, b}`, `IntoIterator` for `S` and `&S`, and order independent
+///`PartialEq` against every `FallibleLinearSet`, `Eq` and `Hash`.
+///Sets offer no `&mut S` iteration, as changing a value could make it equal to another.
+///The first argument lists the generics of the impl, which must name the value type `T`, and the set must keep
+///its backing map in a field named `map`.
+macro_rules! set_traits {
+    ([$($generics:tt)*] $set:ty) => {
+        impl<$($generics)*> std::fmt::Debug for $set
+        where
+            T: std::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_set()
+                    .entries(crate::FallibleLinearSet::values(self))
+                    .finish()
+            }
+        }
+
+        impl<$($generics)*> IntoIterator for $set {
+            type Item = T;
+            type IntoIter = impl Iterator<Item = T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.map.into_iter().map(|(t, ())| t)
+            }
+        }
+
+        impl<'a, $($generics)*> IntoIterator for &'a $set {
+            type Item = &'a T;
+            type IntoIter = impl Iterator<Item = &'a T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                crate::FallibleLinearSet::values(self)
+            }
+        }
+
+        impl<$($generics)*, S: crate::FallibleLinearSet<T> + ?Sized> PartialEq<S> for $set {
+            fn eq(&self, other: &S) -> bool {
+                crate::FallibleLinearSet::len(self) == other.len()
+                    && crate::FallibleLinearSet::values(self).all(|v| other.contains(v))
+            }
+        }
+
+        impl<$($generics)*> Eq for $set {}
+
+        impl<$($generics)*> std::hash::Hash for $set
+        where
+            T: std::hash::Hash,
+        {
+            ///Hashes the values regardless of their order, see `hash_unordered`.
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                crate::std_traits::hash_unordered(crate::FallibleLinearSet::values(self), state)
+            }
+        }
+    };
+}
+
+pub(crate) use {map_index, map_traits, set_traits};
+
+///Returns true if both maps hold the same number of pairs, and every key of `map` is in `other` with an equal value.
+///As neither map holds duplicate keys, this means they hold exactly the same pairs.
+pub(crate) fn eq_unordered<
+    K: Eq,
+    V: PartialEq,
+    M: LinearMapView<K, V> + ?Sized,
+    O: LinearMapView<K, V> + ?Sized,
+>(
+    map: &M,
+    other: &O,
+) -> bool {
+    map.len() == other.len() && map.iter().all(|(k, v)| other.get(k) == Some(v))
+}
+
+///Feeds `state` the number of items and the wrapping sum of the hash of each item, so that the result does not depend
+///on the order of the items. Each item is hashed with the same fixed keys, whatever `state` is, so that collections
+///which compare equal hash identically. Only the count and the sum reach `state`, so nothing is allocated.
+pub(crate) fn hash_unordered<T: Hash, H: Hasher>(items: impl Iterator<Item = T>, state: &mut H) {
+    let (len, sum) = items.fold((0usize, 0u64), |(len, sum), item| {
+        //`DefaultHasher::new` always uses the same keys, unlike `RandomState`.
+        let mut hasher = DefaultHasher::new();
+        item.hash(&mut hasher);
+        (len + 1, sum.wrapping_add(hasher.finish()))
+    });
+
+    state.write_usize(len);
+    state.write_u64(sum);
+}
@@ -0,0 +1,128 @@
+use crate::{
+    AdaptiveBacking, AdaptiveMap, FallibleLinearMap, LinearMapValuesMut, LinearMapView, TryClone,
+    TryFromIterator,
+};
+
+fn sorted<M: FallibleLinearMap<u32, u32>>(map: &M) -> Vec<(u32, u32)> {
+    let mut pairs = map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>();
+    pairs.sort();
+    pairs
+}
+
+#[test]
+fn promotes_past_threshold() {
+    let mut map = AdaptiveMap::<u32, u32, 8>::new();
+    for i in 0..8 {
+        map.insert(i, i * 10).unwrap();
+    }
+    assert!(!map.is_promoted());
+    assert!(map.keys().copied().eq(0..8));
+
+    //updating an existing key never promotes.
+    assert_eq!(map.insert(3, 33).unwrap(), Some(30));
+    assert!(!map.is_promoted());
+
+    map.insert(8, 80).unwrap();
+    assert!(map.is_promoted());
+    assert_eq!(map.len(), 9);
+    assert_eq!(map.get(&3), Some(&33));
+    assert_eq!(map.get(&8), Some(&80));
+    assert_eq!(map.get(&9), None);
+    assert!(matches!(map.into_inner(), AdaptiveBacking::Hashed(h) if h.len() == 9));
+}
+
+#[test]
+fn demotes_below_low_watermark() {
+    let mut map = AdaptiveMap::<u32, u32, 8>::try_from_iter((0..20).map(|i| (i, i))).unwrap();
+    assert!(map.is_promoted());
+
+    //stays promoted until fewer than 4 pairs remain.
+    for i in 0..16 {
+        assert_eq!(map.remove(&i), Some(i));
+        assert!(map.is_promoted());
+    }
+    assert_eq!(map.remove(&16), Some(16));
+    assert!(!map.is_promoted());
+    assert_eq!(sorted(&map), vec![(17, 17), (18, 18), (19, 19)]);
+
+    //pairs inserted after demotion keep their order.
+    map.insert(0, 0).unwrap();
+    assert_eq!(map.nth_key(3), Some(&0));
+}
+
+#[test]
+fn retained_map_demotes_on_next_removal() {
+    let mut map = AdaptiveMap::<u32, u32, 8>::try_from_iter((0..10).map(|i| (i, i))).unwrap();
+    map.retain(|k, _| k % 4 == 0);
+    assert_eq!(sorted(&map), vec![(0, 0), (4, 4), (8, 8)]);
+
+    //`retain` can't demote the map while it borrows it.
+    assert!(map.is_promoted());
+    map.insert(1, 1).unwrap();
+    assert!(map.is_promoted());
+    assert_eq!(map.remove(&0), Some(0));
+    assert!(!map.is_promoted());
+    assert_eq!(sorted(&map), vec![(1, 1), (4, 4), (8, 8)]);
+
+    assert_eq!(map.drain().count(), 3);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn trait_methods_agree_once_promoted() {
+    let mut map = AdaptiveMap::<u32, u32, 2>::new();
+    for i in 0..6 {
+        *map.entry(i).or_try_insert(0).unwrap() += i;
+    }
+    *map.entry(2).or_try_insert(0).unwrap() += 100;
+    assert!(map.is_promoted());
+
+    assert_eq!(LinearMapView::get(&map, &2), Some(&102));
+    *LinearMapValuesMut::get_mut(&mut map, &4).unwrap() += 100;
+    assert_eq!(map.get(&4), Some(&104));
+    assert!(LinearMapView::contains_key(&map, &5));
+
+    match map.entry(1) {
+        crate::Entry::Occupied(o) => assert_eq!(o.remove_entry(), (1, 1)),
+        crate::Entry::Vacant(_) => panic!("1 is in the map"),
+    }
+    assert_eq!(FallibleLinearMap::remove(&mut map, &3), Some(3));
+
+    //every index agrees with the iteration order.
+    for (i, (k, _)) in map.iter().enumerate() {
+        assert_eq!(map.nth_key(i), Some(k));
+    }
+
+    let clone = map.try_clone().unwrap();
+    assert!(clone.is_promoted());
+    assert_eq!(sorted(&clone), vec![(0, 0), (2, 102), (4, 104), (5, 5)]);
+}
+
+#[test]
+fn with_capacity_promotes_up_front() {
+    let small = AdaptiveMap::<u32, u32, 16>::with_capacity(16).unwrap();
+    assert!(!small.is_promoted());
+
+    let mut large = AdaptiveMap::<u32, u32, 16>::with_capacity(17).unwrap();
+    assert!(large.is_promoted());
+    //the copy of an empty table allocates nothing, but is still promoted.
+    assert!(large.try_clone().unwrap().is_promoted());
+    large.insert(0, 0).unwrap();
+    assert!(large.is_promoted());
+}
+
+#[test]
+fn entry_removes_its_own_pair_once_promoted() {
+    let mut map = AdaptiveMap::<u32, u32, 4>::try_from_iter((0..40).map(|i| (i, i * 10))).unwrap();
+    assert!(map.is_promoted());
+
+    for key in (0..40).rev() {
+        match map.entry(key) {
+            crate::Entry::Occupied(o) => assert_eq!(o.remove_entry(), (key, key * 10)),
+            crate::Entry::Vacant(_) => panic!("{key} is in the map"),
+        }
+        assert!(!map.contains_key(&key));
+        assert_eq!(map.len(), key as usize);
+    }
+    assert!(!map.is_promoted());
+}
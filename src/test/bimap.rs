@@ -0,0 +1,121 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{
+    stack_list::error::PushError, BiStackMap, BiVecMap, FallibleLinearBiMap, Overwritten, TryClone,
+    TryFromIterator,
+};
+
+fn pairs<M: FallibleLinearBiMap<i32, &'static str>>(map: &M) -> Vec<(i32, &'static str)> {
+    map.iter().copied().collect()
+}
+
+fn hash_of<T: Hash>(t: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    t.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn lookups<M: FallibleLinearBiMap<i32, &'static str>>(mut map: M) {
+    assert_eq!(map.insert(1, "one").unwrap(), Overwritten::Neither);
+    assert_eq!(map.insert(2, "two").unwrap(), Overwritten::Neither);
+
+    assert_eq!(map.get_by_left(&1), Some(&"one"));
+    assert_eq!(map.get_by_right(&"two"), Some(&2));
+    assert_eq!(map.get_by_left(&3), None);
+    assert_eq!(map.get_by_right(&"three"), None);
+    assert!(map.contains_left(&2));
+    assert!(map.contains_right(&"one"));
+    assert!(!map.contains_right(&"three"));
+
+    assert_eq!(map.remove_by_right(&"one"), Some((1, "one")));
+    assert_eq!(map.remove_by_left(&1), None);
+    assert_eq!(map.remove_by_left(&2), Some((2, "two")));
+    assert!(map.is_empty());
+}
+
+fn overwrites<M: FallibleLinearBiMap<i32, &'static str>>(mut map: M) {
+    map.insert(1, "one").unwrap();
+    map.insert(2, "two").unwrap();
+    map.insert(3, "three").unwrap();
+
+    assert_eq!(map.insert(1, "uno").unwrap(), Overwritten::Left(1, "one"));
+    assert_eq!(map.insert(20, "two").unwrap(), Overwritten::Right(2, "two"));
+    assert_eq!(
+        map.insert(3, "three").unwrap(),
+        Overwritten::Pair(3, "three")
+    );
+    assert_eq!(pairs(&map), vec![(1, "uno"), (20, "two"), (3, "three")]);
+
+    let both = map.insert(1, "three").unwrap();
+    assert_eq!(both, Overwritten::Both((1, "uno"), (3, "three")));
+    assert!(both.did_overwrite());
+    assert_eq!(pairs(&map), vec![(1, "three"), (20, "two")]);
+    assert_eq!(map.get_by_right(&"three"), Some(&1));
+    assert_eq!(map.get_by_right(&"uno"), None);
+    assert_eq!(map.get_by_left(&3), None);
+}
+
+#[test]
+fn bi_vec_map() {
+    lookups(BiVecMap::new());
+    overwrites(BiVecMap::new());
+}
+
+#[test]
+fn bi_stack_map() {
+    lookups(BiStackMap::<_, _, 2>::new());
+    overwrites(BiStackMap::<_, _, 3>::new());
+}
+
+#[test]
+fn bi_stack_map_full() {
+    let mut map = BiStackMap::<i32, &str, 2>::try_from_iter([(1, "one"), (2, "two")]).unwrap();
+
+    assert!(matches!(
+        map.insert(3, "three"),
+        Err(PushError::WouldExceedCapacity)
+    ));
+    //displacing insertions reuse the space of the displaced pair.
+    assert_eq!(map.insert(3, "two").unwrap(), Overwritten::Right(2, "two"));
+    assert_eq!(
+        map.insert(1, "two").unwrap(),
+        Overwritten::Both((1, "one"), (3, "two"))
+    );
+    assert_eq!(pairs(&map), vec![(1, "two")]);
+}
+
+#[test]
+fn bi_map_from_iter_displaces() {
+    let map = BiVecMap::try_from_iter([(1, "one"), (2, "two"), (1, "two")]).unwrap();
+
+    assert_eq!(pairs(&map), vec![(1, "two")]);
+    assert_eq!(map.try_clone().unwrap(), map);
+    assert_eq!(map.lefts().copied().collect::<Vec<_>>(), vec![1]);
+    assert_eq!(map.rights().copied().collect::<Vec<_>>(), vec!["two"]);
+}
+
+#[test]
+fn bi_map_eq_ignores_order() {
+    let map = BiVecMap::try_from_iter([(1, "one"), (2, "two")]).unwrap();
+    let reversed = BiVecMap::try_from_iter([(2, "two"), (1, "one")]).unwrap();
+    let stack = BiStackMap::<_, _, 2>::try_from_iter([(2, "two"), (1, "one")]).unwrap();
+
+    assert_eq!(map, reversed);
+    assert_eq!(map, stack);
+    assert_eq!(hash_of(&map), hash_of(&reversed));
+    assert_ne!(
+        map,
+        BiVecMap::try_from_iter([(1, "two"), (2, "one")]).unwrap()
+    );
+    assert_ne!(map, BiVecMap::try_from_iter([(1, "one")]).unwrap());
+}
+
+#[test]
+fn bi_map_default_does_not_require_default_pairs() {
+    #[derive(PartialEq, Eq)]
+    struct NoDefault;
+
+    let mut map = BiVecMap::<NoDefault, i32>::default();
+    map.insert(NoDefault, 1).unwrap();
+    assert_eq!(map.len(), 1);
+}
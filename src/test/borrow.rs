@@ -0,0 +1,98 @@
+use crate::{
+    array::map::ArrayMap, FallibleLinearMap, FallibleLinearSet, FatMap, LinearMapValuesMut,
+    LinearMapView, StackSet, VecMap, VecSet,
+};
+
+fn string_map() -> VecMap<String, i32> {
+    let mut m = VecMap::new();
+    m.insert("one".to_string(), 1).unwrap();
+    m.insert("two".to_string(), 2).unwrap();
+    m
+}
+
+#[test]
+fn borrowed_get() {
+    let mut m = string_map();
+
+    assert_eq!(m.get("one"), Some(&1));
+    assert_eq!(m.get("three"), None);
+
+    *m.get_mut("two").unwrap() = 22;
+    assert_eq!(m.get("two"), Some(&22));
+}
+
+#[test]
+fn borrowed_contains_key() {
+    let m = string_map();
+
+    assert!(m.contains_key("one"));
+    assert!(!m.contains_key("three"));
+}
+
+#[test]
+fn borrowed_remove_entry() {
+    let mut m = string_map();
+
+    assert_eq!(m.remove_entry("one"), Some(("one".to_string(), 1)));
+    assert_eq!(m.remove("one"), None);
+    assert_eq!(m.remove("two"), Some(2));
+    assert!(m.is_empty());
+}
+
+#[test]
+fn borrowed_replace() {
+    let mut m: FatMap<String, i32, 1> = FatMap::new();
+    m.insert("a".to_string(), 1).unwrap();
+
+    m.replace("a", 10);
+    m.replace("b", 20);
+
+    assert_eq!(m.get("a"), Some(&10));
+    assert_eq!(m.get("b"), None);
+}
+
+#[test]
+fn insert_ref_updates_existing() {
+    let mut m = string_map();
+
+    assert_eq!(m.insert_ref("one", 11), Ok(Some(1)));
+    assert_eq!(m.len(), 2);
+    assert_eq!(m.get("one"), Some(&11));
+}
+
+#[test]
+fn insert_ref_inserts_missing() {
+    let mut m = string_map();
+
+    assert_eq!(m.insert_ref("three", 3), Ok(None));
+    assert_eq!(m.len(), 3);
+    assert_eq!(m.get("three"), Some(&3));
+}
+
+#[test]
+fn borrowed_set_lookups() {
+    let mut set: VecSet<String> = VecSet::new();
+    set.insert("a".to_string()).unwrap();
+
+    assert!(set.contains("a"));
+    assert!(!set.contains("b"));
+    assert_eq!(set.remove("a"), Some("a".to_string()));
+    assert!(set.is_empty());
+
+    let mut set: StackSet<Vec<u8>, 2> = StackSet::new();
+    set.insert(vec![1, 2]).unwrap();
+
+    assert!(set.contains([1u8, 2].as_slice()));
+    assert_eq!(set.remove([1u8, 2].as_slice()), Some(vec![1, 2]));
+}
+
+#[test]
+fn borrowed_array_map_get() {
+    let mut m =
+        unsafe { ArrayMap::from_array_unchecked([("a".to_string(), 1), ("b".to_string(), 2)]) };
+
+    assert_eq!(m.get("b"), Some(&2));
+    *m.get_mut("a").unwrap() = 10;
+    assert_eq!(m.get("a"), Some(&10));
+    assert_eq!(m.get("c"), None);
+}
@@ -0,0 +1,107 @@
+use crate::{
+    stack_list::error::PushError, DequeMap, Entry, FallibleLinearMap, FatMap, LinearMapView,
+    StackMap, VecMap,
+};
+
+#[test]
+fn entry_vacant_or_try_insert() {
+    let mut m: VecMap<&str, i32> = VecMap::new();
+
+    *m.entry("a").or_try_insert(1).unwrap() += 10;
+
+    assert_eq!(m.get(&"a"), Some(&11));
+    assert_eq!(m.len(), 1);
+}
+
+#[test]
+fn entry_occupied_or_try_insert_keeps_value() {
+    let mut m: DequeMap<&str, i32> = DequeMap::new();
+    m.insert("a", 1).unwrap();
+
+    assert_eq!(*m.entry("a").or_try_insert(5).unwrap(), 1);
+    assert_eq!(m.len(), 1);
+}
+
+#[test]
+fn entry_or_try_insert_with_is_lazy() {
+    let mut m: FatMap<i32, i32, 2> = FatMap::new();
+    m.insert(0, 0).unwrap();
+
+    m.entry(0)
+        .or_try_insert_with(|| panic!("entry was occupied"))
+        .unwrap();
+
+    //the third pair spills onto the heap.
+    m.insert(1, 1).unwrap();
+    assert_eq!(*m.entry(2).or_try_insert_with(|| 2).unwrap(), 2);
+    assert_eq!(m.get(&2), Some(&2));
+}
+
+#[test]
+fn entry_and_modify() {
+    let mut m: VecMap<char, u32> = VecMap::new();
+
+    for c in "abca".chars() {
+        m.entry(c).and_modify(|v| *v += 1).or_try_insert(1).unwrap();
+    }
+
+    assert_eq!(m.get(&'a'), Some(&2));
+    assert_eq!(m.get(&'b'), Some(&1));
+    assert_eq!(m.get(&'c'), Some(&1));
+}
+
+#[test]
+fn entry_try_insert_overwrites() {
+    let mut m: VecMap<i32, &str> = VecMap::new();
+    m.insert(1, "one").unwrap();
+
+    let occupied = m.entry(1).try_insert("uno").unwrap();
+    assert_eq!(occupied.get(), &"uno");
+
+    let occupied = m.entry(2).try_insert("dos").unwrap();
+    assert_eq!(occupied.key(), &2);
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+fn entry_occupied_remove() {
+    let mut m: StackMap<i32, i32, 4> = StackMap::new();
+    m.insert(1, 10).unwrap();
+    m.insert(2, 20).unwrap();
+    m.insert(3, 30).unwrap();
+
+    match m.entry(2) {
+        Entry::Occupied(o) => assert_eq!(o.remove_entry(), (2, 20)),
+        Entry::Vacant(_) => panic!("entry should be occupied"),
+    }
+
+    assert_eq!(m.len(), 2);
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), vec![1, 3]);
+}
+
+#[test]
+fn entry_vacant_into_key() {
+    let mut m: VecMap<String, i32> = VecMap::new();
+
+    match m.entry("k".to_string()) {
+        Entry::Vacant(v) => assert_eq!(v.into_key(), "k"),
+        Entry::Occupied(_) => panic!("entry should be vacant"),
+    }
+
+    assert!(m.is_empty());
+}
+
+#[test]
+fn entry_stack_map_full_errors() {
+    let mut m: StackMap<i32, i32, 1> = StackMap::new();
+    m.insert(1, 1).unwrap();
+
+    //occupied entries never need to push, even when full.
+    assert_eq!(*m.entry(1).or_try_insert(0).unwrap(), 1);
+
+    assert!(matches!(
+        m.entry(2).or_try_insert(2),
+        Err(PushError::WouldExceedCapacity)
+    ));
+    assert_eq!(m.len(), 1);
+}
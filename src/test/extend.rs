@@ -0,0 +1,89 @@
+use crate::{
+    stack_list::{error::PushError, StackList},
+    DequeMap, FallibleLinearMap, FallibleLinearSet, FatMap, FatSet, FatVec, LinearMapView,
+    StackMap, StackSet, TryFromIterator, VecMap, VecSet,
+};
+
+#[test]
+fn map_try_from_iter_later_duplicates_update() {
+    let map = VecMap::try_from_iter([(1, "a"), (2, "b"), (1, "c")]).unwrap();
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&1), Some(&"c"));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![1, 2]);
+}
+
+#[test]
+fn map_try_extend_appends() {
+    let mut map: DequeMap<i32, i32> = DequeMap::new();
+    map.insert(0, 0).unwrap();
+
+    map.try_extend((1..4).map(|i| (i, i))).unwrap();
+    map.try_extend([(0, 10)]).unwrap();
+
+    assert_eq!(map.len(), 4);
+    assert_eq!(map.get(&0), Some(&10));
+}
+
+#[test]
+fn fat_map_try_from_iter_spills_onto_heap() {
+    let map: FatMap<i32, i32, 2> = FatMap::try_from_iter((0..5).map(|i| (i, i * 2))).unwrap();
+
+    assert_eq!(
+        map.values().copied().collect::<Vec<_>>(),
+        vec![0, 2, 4, 6, 8]
+    );
+}
+
+#[test]
+fn stack_map_duplicates_fit_beyond_size_hint() {
+    //the iterator yields more pairs than the map can hold, but only three distinct keys.
+    let map: StackMap<i32, i32, 3> =
+        StackMap::try_from_iter([(1, 1), (2, 2), (1, 3), (3, 3), (2, 4)]).unwrap();
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get(&2), Some(&4));
+}
+
+#[test]
+fn stack_map_reports_consumed_items() {
+    let mut map: StackMap<i32, i32, 2> = StackMap::new();
+    let mut iter = (0..5).map(|i| (i, i));
+
+    let error = map.try_extend(iter.by_ref()).unwrap_err();
+
+    assert!(matches!(error.error(), PushError::WouldExceedCapacity));
+    assert_eq!(error.consumed(), 3);
+    assert_eq!(map.len(), 2);
+    assert_eq!(iter.next(), Some((3, 3)));
+}
+
+#[test]
+fn set_try_from_iter_ignores_duplicates() {
+    let set = VecSet::try_from_iter([3, 1, 3, 2, 1]).unwrap();
+    assert_eq!(set.values().copied().collect::<Vec<_>>(), vec![3, 1, 2]);
+
+    let mut fat: FatSet<i32, 2> = FatSet::new();
+    fat.try_extend(0..4).unwrap();
+    assert_eq!(fat.len(), 4);
+
+    match StackSet::<i32, 2>::try_from_iter(0..4) {
+        Err(error) => assert_eq!(error.consumed(), 3),
+        Ok(_) => panic!("a StackSet of capacity 2 cannot hold 4 values"),
+    }
+}
+
+#[test]
+fn fat_vec_and_stack_list_try_extend() {
+    let mut fatvec: FatVec<i32, 3> = FatVec::try_from_iter(0..2).unwrap();
+    fatvec.try_extend(2..6).unwrap();
+    assert_eq!(
+        fatvec.iter().copied().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 4, 5]
+    );
+
+    let mut list: StackList<i32, 4> = StackList::try_from_iter(0..3).unwrap();
+    let error = list.try_extend(3..10).unwrap_err();
+    assert_eq!(error.consumed(), 2);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+}
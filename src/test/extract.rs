@@ -0,0 +1,146 @@
+use std::rc::Rc;
+
+use crate::{
+    AdaptiveMap, DequeMap, DequeSet, FallibleLinearMap, FallibleLinearSet, FatMap,
+    FingerprintVecMap, SoaFatMap, SoaStackMap, SoaVecMap, StackMap, VecMap, VecSet,
+};
+
+fn fill<M: FallibleLinearMap<i32, i32>>(map: &mut M, len: i32) {
+    for i in 0..len {
+        map.insert(i, i * 10).unwrap();
+    }
+}
+
+fn keys<M: FallibleLinearMap<i32, i32>>(map: &M) -> Vec<i32> {
+    map.keys().copied().collect()
+}
+
+fn retain_keeps_order<M: FallibleLinearMap<i32, i32>>(mut map: M) {
+    fill(&mut map, 8);
+
+    map.retain(|k, v| {
+        *v += 1;
+        k % 3 != 0
+    });
+
+    assert_eq!(keys(&map), vec![1, 2, 4, 5, 7]);
+    assert_eq!(map.get(&4), Some(&41));
+}
+
+#[test]
+fn retain_on_every_map() {
+    retain_keeps_order(VecMap::new());
+    retain_keeps_order(DequeMap::new());
+    retain_keeps_order(StackMap::<i32, i32, 8>::new());
+    //spans both the stack and the heap.
+    retain_keeps_order(FatMap::<i32, i32, 3>::new());
+    retain_keeps_order(SoaVecMap::new());
+    retain_keeps_order(FingerprintVecMap::new());
+    retain_keeps_order(AdaptiveMap::<i32, i32, 8>::new());
+    retain_keeps_order(SoaStackMap::<i32, i32, 8>::new());
+    retain_keeps_order(SoaFatMap::<i32, i32, 3>::new());
+}
+
+fn extract_if_dropped_early<M: FallibleLinearMap<i32, i32>>(mut map: M) {
+    fill(&mut map, 8);
+
+    {
+        let mut extracted = map.extract_if(|k, _| k % 2 == 1);
+        assert_eq!(extracted.next(), Some((1, 10)));
+        assert_eq!(extracted.next(), Some((3, 30)));
+    }
+
+    assert_eq!(keys(&map), vec![0, 2, 4, 5, 6, 7]);
+
+    //the map is fully usable afterwards.
+    map.insert(9, 90).unwrap();
+    assert_eq!(map.remove(&5), Some(50));
+    assert_eq!(keys(&map), vec![0, 2, 4, 6, 7, 9]);
+}
+
+#[test]
+fn extract_if_keeps_unvisited_on_every_map() {
+    extract_if_dropped_early(VecMap::new());
+    extract_if_dropped_early(DequeMap::new());
+    extract_if_dropped_early(StackMap::<i32, i32, 10>::new());
+    extract_if_dropped_early(FatMap::<i32, i32, 3>::new());
+    extract_if_dropped_early(SoaVecMap::new());
+    extract_if_dropped_early(FingerprintVecMap::new());
+    extract_if_dropped_early(AdaptiveMap::<i32, i32, 10>::new());
+    extract_if_dropped_early(SoaStackMap::<i32, i32, 10>::new());
+    extract_if_dropped_early(SoaFatMap::<i32, i32, 3>::new());
+}
+
+fn drain_empties<M: FallibleLinearMap<i32, i32>>(mut map: M) {
+    fill(&mut map, 6);
+
+    assert_eq!(
+        map.drain().take(2).collect::<Vec<_>>(),
+        vec![(0, 0), (1, 10)]
+    );
+    assert!(map.is_empty());
+
+    fill(&mut map, 2);
+    assert_eq!(keys(&map), vec![0, 1]);
+}
+
+#[test]
+fn drain_on_every_map() {
+    drain_empties(VecMap::new());
+    drain_empties(DequeMap::new());
+    drain_empties(StackMap::<i32, i32, 6>::new());
+    drain_empties(FatMap::<i32, i32, 4>::new());
+    drain_empties(SoaVecMap::new());
+    drain_empties(FingerprintVecMap::new());
+    drain_empties(AdaptiveMap::<i32, i32, 6>::new());
+    drain_empties(SoaStackMap::<i32, i32, 6>::new());
+    drain_empties(SoaFatMap::<i32, i32, 4>::new());
+}
+
+#[test]
+fn extract_if_drops_each_value_once() {
+    let tracker = Rc::new(());
+    let mut stack: StackMap<i32, Rc<()>, 6> = StackMap::new();
+    let mut fat: FatMap<i32, Rc<()>, 2> = FatMap::new();
+    let mut soa: SoaFatMap<i32, Rc<()>, 2> = SoaFatMap::new();
+
+    for i in 0..6 {
+        stack.insert(i, tracker.clone()).unwrap();
+        fat.insert(i, tracker.clone()).unwrap();
+        soa.insert(i, tracker.clone()).unwrap();
+    }
+    assert_eq!(Rc::strong_count(&tracker), 19);
+
+    stack.retain(|k, _| k % 2 == 0);
+    fat.extract_if(|k, _| *k < 3).take(1).for_each(drop);
+    soa.extract_if(|k, _| *k % 3 == 1).for_each(drop);
+    assert_eq!(Rc::strong_count(&tracker), 1 + 3 + 5 + 4);
+}
+
+#[test]
+fn set_retain_extract_drain() {
+    let mut set: VecSet<i32> = VecSet::new();
+    let mut deque: DequeSet<i32> = DequeSet::new();
+    for i in 0..6 {
+        set.insert(i).unwrap();
+        deque.insert(i).unwrap();
+    }
+
+    set.retain(|v| *v != 2);
+    assert_eq!(
+        set.values().copied().collect::<Vec<_>>(),
+        vec![0, 1, 3, 4, 5]
+    );
+
+    assert_eq!(
+        deque.extract_if(|v| *v >= 4).collect::<Vec<_>>(),
+        vec![4, 5]
+    );
+    assert_eq!(
+        deque.values().copied().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3]
+    );
+
+    assert_eq!(set.drain().count(), 5);
+    assert!(set.is_empty());
+}
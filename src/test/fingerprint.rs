@@ -0,0 +1,99 @@
+use std::{
+    cell::Cell,
+    hash::{BuildHasherDefault, DefaultHasher, Hash, Hasher},
+};
+
+use crate::{FallibleLinearMap, FingerprintVecMap, LinearMapView, TryClone, TryFromIterator};
+
+///A key which counts how many times it is compared for equality.
+struct Counted<'a> {
+    name: String,
+    comparisons: &'a Cell<usize>,
+}
+
+impl PartialEq for Counted<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.comparisons.set(self.comparisons.get() + 1);
+        self.name == other.name
+    }
+}
+
+impl Eq for Counted<'_> {}
+
+impl Hash for Counted<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state)
+    }
+}
+
+///Hashes every key to the same value, so that every fingerprint collides.
+#[derive(Default)]
+struct Collide;
+
+impl Hasher for Collide {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, _: &[u8]) {}
+}
+
+#[test]
+fn fingerprints_skip_full_comparisons() {
+    let comparisons = Cell::new(0);
+    let key = |i: usize| Counted {
+        name: format!("a long shared prefix for key number {i}"),
+        comparisons: &comparisons,
+    };
+
+    //a fixed hasher, so that the fingerprints are the same on every run.
+    let mut map = FingerprintVecMap::with_hasher(BuildHasherDefault::<DefaultHasher>::default());
+    for i in 0..64 {
+        map.insert(key(i), i).unwrap();
+    }
+    comparisons.set(0);
+
+    for i in 0..64 {
+        assert_eq!(map.get(&key(i)), Some(&i));
+        assert_eq!(map.get(&key(i + 64)), None);
+    }
+
+    //one comparison for every hit and none for the misses, bar the odd colliding fingerprint.
+    //A plain scan makes over 6000.
+    assert!(comparisons.get() <= 64 + 4);
+}
+
+#[test]
+fn colliding_fingerprints_compare_keys() {
+    let mut map = FingerprintVecMap::with_hasher(BuildHasherDefault::<Collide>::default());
+    for c in "linear".chars() {
+        map.insert(c.to_string(), c).unwrap();
+    }
+
+    assert_eq!(map.get("e"), Some(&'e'));
+    assert_eq!(map.insert("e".to_string(), 'E').unwrap(), Some('e'));
+    assert_eq!(map.remove("l"), Some('l'));
+    assert_eq!(map.get("l"), None);
+    assert!(map.contains_key("r"));
+    assert_eq!(map.keys().cloned().collect::<String>(), "inear");
+}
+
+#[test]
+fn fingerprint_map_borrowed_lookups() {
+    let mut map =
+        FingerprintVecMap::<String, usize>::try_from_iter([("a".to_string(), 1)]).unwrap();
+
+    assert_eq!(map.insert_ref("b", 2).unwrap(), None);
+    *map.entry("a".to_string()).or_try_insert(0).unwrap() += 10;
+    *map.get_mut("b").unwrap() += 20;
+
+    //the trait lookups are answered by a plain scan, but must agree.
+    assert_eq!(LinearMapView::get(&map, "a"), Some(&11));
+    assert_eq!(map.get("b"), Some(&22));
+
+    let clone = map.try_clone().unwrap();
+    assert_eq!(FallibleLinearMap::remove(&mut map, "a"), Some(11));
+    assert_eq!(map.remove_entry("b"), Some(("b".to_string(), 22)));
+    assert!(map.is_empty());
+    assert_eq!(clone.len(), 2);
+}
@@ -0,0 +1,104 @@
+use crate::{LinearLru, StackLru, TryClone};
+
+macro_rules! lru_tests {
+    ($name:ident, $new:expr) => {
+        mod $name {
+            use super::*;
+
+            fn keys<'a>(iter: impl Iterator<Item = (&'a i32, &'a i32)>) -> Vec<i32> {
+                iter.map(|(k, _)| *k).collect()
+            }
+
+            #[test]
+            fn put_evicts_least_recently_used() {
+                let mut lru = $new;
+                assert_eq!(lru.put(1, 10), None);
+                assert_eq!(lru.put(2, 20), None);
+                assert_eq!(lru.put(3, 30), None);
+                assert_eq!(lru.len(), 3);
+
+                assert_eq!(lru.put(4, 40), Some((1, 10)));
+                assert_eq!(keys(lru.iter()), vec![2, 3, 4]);
+                assert_eq!(lru.len(), lru.capacity());
+            }
+
+            #[test]
+            fn put_existing_key_returns_old_pair() {
+                let mut lru = $new;
+                lru.put(1, 10);
+                lru.put(2, 20);
+
+                assert_eq!(lru.put(1, 11), Some((1, 10)));
+                assert_eq!(keys(lru.iter()), vec![2, 1]);
+                assert_eq!(lru.peek(&1), Some(&11));
+            }
+
+            #[test]
+            fn get_promotes_and_peek_does_not() {
+                let mut lru = $new;
+                lru.put(1, 10);
+                lru.put(2, 20);
+                lru.put(3, 30);
+
+                assert_eq!(lru.peek(&1), Some(&10));
+                assert!(lru.contains_key(&1));
+                assert_eq!(keys(lru.iter()), vec![1, 2, 3]);
+
+                assert_eq!(lru.get(&1), Some(&10));
+                assert_eq!(keys(lru.iter()), vec![2, 3, 1]);
+                *lru.get_mut(&2).unwrap() += 1;
+                assert_eq!(keys(lru.iter()), vec![3, 1, 2]);
+                assert_eq!(lru.get(&9), None);
+
+                //3 is now the least recently used.
+                assert_eq!(lru.put(4, 40), Some((3, 30)));
+                assert_eq!(lru.peek(&2), Some(&21));
+            }
+
+            #[test]
+            fn remove_and_pop() {
+                let mut lru = $new;
+                lru.put(1, 10);
+                lru.put(2, 20);
+                lru.put(3, 30);
+
+                assert_eq!(lru.remove(&2), Some(20));
+                assert_eq!(lru.remove(&2), None);
+                assert_eq!(lru.pop_lru(), Some((1, 10)));
+                assert_eq!(keys(lru.iter()), vec![3]);
+
+                lru.clear();
+                assert!(lru.is_empty());
+                assert_eq!(lru.pop_lru(), None);
+            }
+
+            #[test]
+            fn try_clone_keeps_order() {
+                let mut lru = $new;
+                lru.put(1, 10);
+                lru.put(2, 20);
+                lru.get(&1);
+
+                let mut clone = lru.try_clone().unwrap();
+                assert_eq!(keys(clone.iter()), vec![2, 1]);
+                assert_eq!(clone.capacity(), 3);
+                clone.put(3, 30);
+                assert_eq!(clone.put(4, 40), Some((2, 20)));
+            }
+        }
+    };
+}
+
+lru_tests!(linear, LinearLru::<i32, i32>::with_capacity(3).unwrap());
+lru_tests!(stack, StackLru::<i32, i32, 3>::new());
+
+#[test]
+fn zero_capacity_hands_back_pair() {
+    let mut linear = LinearLru::with_capacity(0).unwrap();
+    let mut stack = StackLru::<_, _, 0>::new();
+
+    assert_eq!(linear.put(1, 10), Some((1, 10)));
+    assert_eq!(stack.put(1, 10), Some((1, 10)));
+    assert!(linear.is_empty());
+    assert!(stack.is_empty());
+}
@@ -0,0 +1,88 @@
+use crate::{FallibleLinearMultiMap, FatMultiMap, TryClone, TryFromIterator, VecMultiMap};
+
+const HEADERS: [(&str, i32); 5] = [
+    ("accept", 1),
+    ("cookie", 2),
+    ("accept", 3),
+    ("host", 4),
+    ("accept", 5),
+];
+
+fn headers<M: FallibleLinearMultiMap<&'static str, i32>>(mut map: M) -> M {
+    for (k, v) in HEADERS {
+        map.insert(k, v).unwrap();
+    }
+    map
+}
+
+fn lookups<M: FallibleLinearMultiMap<&'static str, i32>>(map: M) {
+    let mut map = headers(map);
+    assert_eq!(map.len(), 5);
+
+    assert_eq!(
+        map.get_all(&"accept").copied().collect::<Vec<_>>(),
+        vec![1, 3, 5]
+    );
+    assert_eq!(map.get_all(&"missing").count(), 0);
+    assert_eq!(map.get_first(&"accept"), Some(&1));
+    assert_eq!(map.get_last(&"accept"), Some(&5));
+    assert_eq!(map.get_last(&"host"), Some(&4));
+    assert_eq!(map.get_first(&"missing"), None);
+    assert!(map.contains_key(&"cookie"));
+
+    map.get_all_mut(&"accept").for_each(|v| *v *= 10);
+    assert_eq!(
+        map.get_all(&"accept").copied().collect::<Vec<_>>(),
+        vec![10, 30, 50]
+    );
+}
+
+fn remove_all<M: FallibleLinearMultiMap<&'static str, i32>>(map: M) {
+    let mut map = headers(map);
+
+    assert_eq!(map.remove_all(&"accept").collect::<Vec<_>>(), vec![1, 3, 5]);
+    assert!(!map.contains_key(&"accept"));
+    assert_eq!(map.remove_all(&"accept").count(), 0);
+
+    //dropping the iterator early still removes every value.
+    map.insert("cookie", 6).unwrap();
+    assert_eq!(map.remove_all(&"cookie").next(), Some(2));
+    assert!(!map.contains_key(&"cookie"));
+
+    assert_eq!(map.iter().copied().collect::<Vec<_>>(), vec![("host", 4)]);
+}
+
+#[test]
+fn vec_multimap() {
+    lookups(VecMultiMap::new());
+    remove_all(VecMultiMap::new());
+}
+
+#[test]
+fn fat_multimap() {
+    //spills onto the heap part way through.
+    lookups(FatMultiMap::<_, _, 2>::new());
+    remove_all(FatMultiMap::<_, _, 2>::new());
+}
+
+#[test]
+fn multimap_from_iter_keeps_order() {
+    let vec = VecMultiMap::try_from_iter(HEADERS).unwrap();
+    let fat = FatMultiMap::<_, _, 3>::try_from_iter(HEADERS).unwrap();
+
+    assert_eq!(vec.as_slice(), HEADERS);
+    assert!(fat.iter().eq(HEADERS.iter()));
+    assert_eq!(vec.try_clone().unwrap(), vec);
+    assert_eq!(fat.try_clone().unwrap(), fat);
+    assert_eq!(vec.into_inner(), HEADERS.to_vec());
+}
+
+#[test]
+fn multimap_default_does_not_require_default_pairs() {
+    #[derive(PartialEq, Eq)]
+    struct NoDefault;
+
+    let mut map = VecMultiMap::<NoDefault, NoDefault>::default();
+    map.insert(NoDefault, NoDefault).unwrap();
+    assert_eq!(map.len(), 1);
+}
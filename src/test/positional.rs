@@ -0,0 +1,84 @@
+use crate::{
+    DequeMap, FallibleLinearMap, FatMap, PositionalLinearMap, SoaFatMap, SoaStackMap, SoaVecMap,
+    StackMap, TryFromIterator, VecMap,
+};
+
+const PAIRS: [(char, i32); 5] = [('a', 0), ('b', 1), ('c', 2), ('d', 3), ('e', 4)];
+
+fn keys<M: FallibleLinearMap<char, i32>>(map: &M) -> String {
+    map.keys().collect()
+}
+
+fn positions<M: PositionalLinearMap<char, i32>>(mut map: M) {
+    assert_eq!(map.get_index(1), Some((&'b', &1)));
+    assert_eq!(map.get_index(5), None);
+    *map.get_index_mut(4).unwrap().1 += 10;
+    assert_eq!(map.get_index_mut(5), None);
+    assert_eq!(map.get_index_of(&'e'), Some(4));
+    assert_eq!(map.get_index_of(&'z'), None);
+    assert_eq!(map.nth_value(4), Some(&14));
+    assert_eq!(map.nth_key(5), None);
+
+    assert!(map.swap_indices(0, 4));
+    assert!(!map.swap_indices(0, 5));
+    assert_eq!(keys(&map), "ebcda");
+
+    assert!(map.move_index(0, 3));
+    assert_eq!(keys(&map), "bcdea");
+    assert!(map.move_index(4, 1));
+    assert_eq!(keys(&map), "bacde");
+    assert!(map.move_index(2, 2));
+    assert!(!map.move_index(5, 0));
+    assert_eq!(keys(&map), "bacde");
+}
+
+fn removals<M: PositionalLinearMap<char, i32>>(mut map: M) {
+    assert_eq!(map.swap_remove(&'b'), Some(1));
+    assert_eq!(keys(&map), "aecd");
+    assert_eq!(map.swap_remove(&'b'), None);
+    //the last pair swaps with itself.
+    assert_eq!(map.swap_remove_index(3), Some(('d', 3)));
+    assert_eq!(map.swap_remove_index(3), None);
+
+    assert_eq!(map.shift_remove(&'a'), Some(0));
+    assert_eq!(keys(&map), "ec");
+    assert_eq!(map.shift_remove_index(2), None);
+    assert_eq!(map.shift_remove_index(1), Some(('c', 2)));
+    assert_eq!(map.swap_remove_index(0), Some(('e', 4)));
+    assert_eq!(map.swap_remove_index(0), None);
+}
+
+#[test]
+fn vec_map_positions() {
+    positions(VecMap::try_from_iter(PAIRS).unwrap());
+    removals(VecMap::try_from_iter(PAIRS).unwrap());
+}
+
+#[test]
+fn deque_map_positions() {
+    positions(DequeMap::try_from_iter(PAIRS).unwrap());
+    removals(DequeMap::try_from_iter(PAIRS).unwrap());
+}
+
+#[test]
+fn stack_map_positions() {
+    positions(StackMap::<_, _, 5>::try_from_iter(PAIRS).unwrap());
+    removals(StackMap::<_, _, 8>::try_from_iter(PAIRS).unwrap());
+}
+
+#[test]
+fn fat_map_positions() {
+    //pairs move across the stack and heap boundary.
+    positions(FatMap::<_, _, 2>::try_from_iter(PAIRS).unwrap());
+    removals(FatMap::<_, _, 2>::try_from_iter(PAIRS).unwrap());
+}
+
+#[test]
+fn soa_map_positions() {
+    positions(SoaVecMap::try_from_iter(PAIRS).unwrap());
+    removals(SoaVecMap::try_from_iter(PAIRS).unwrap());
+    positions(SoaStackMap::<_, _, 5>::try_from_iter(PAIRS).unwrap());
+    removals(SoaStackMap::<_, _, 8>::try_from_iter(PAIRS).unwrap());
+    positions(SoaFatMap::<_, _, 2>::try_from_iter(PAIRS).unwrap());
+    removals(SoaFatMap::<_, _, 2>::try_from_iter(PAIRS).unwrap());
+}
@@ -0,0 +1,152 @@
+use crate::{
+    array::map::ArrayMap, DequeMap, FallibleLinearMap, FatMap, LinearMapValuesMut, LinearMapView,
+    StackMap, VecMap,
+};
+
+///Checks every present key, plus keys just outside the map, against a linear scan.
+macro_rules! check_stack_map {
+    ($key:ty, $($n:literal),*) => {$({
+        let mut map = StackMap::<$key, usize, $n>::new();
+        for i in 0..$n {
+            map.insert((i * 3) as $key, i).unwrap();
+        }
+
+        for i in 0..$n {
+            assert_eq!(map.get(&((i * 3) as $key)), Some(&i));
+            assert_eq!(map.get(&((i * 3 + 1) as $key)), None);
+            *map.get_mut(&((i * 3) as $key)).unwrap() += 1;
+            assert_eq!(map.get(&((i * 3) as $key)), Some(&(i + 1)));
+        }
+        assert_eq!(map.get(&(($n * 3) as $key)), None);
+    })*};
+}
+
+//zero length maps expand to empty ranges.
+#[allow(clippy::reversed_empty_ranges)]
+#[test]
+fn stack_map_primitive_keys() {
+    check_stack_map!(u8, 0, 1, 15, 16, 17, 33);
+    check_stack_map!(i8, 1, 16, 17);
+    check_stack_map!(u16, 0, 7, 8, 9, 20);
+    check_stack_map!(i32, 3, 4, 5, 13);
+    check_stack_map!(u32, 4, 8, 31);
+    check_stack_map!(u64, 1, 2, 3, 9);
+    check_stack_map!(isize, 2, 5);
+    check_stack_map!(usize, 0, 7);
+}
+
+#[test]
+fn stack_map_partially_filled() {
+    let mut map = StackMap::<i16, usize, 40>::new();
+
+    for i in 0..40 {
+        let key = -(i as i16) * 7;
+        assert_eq!(map.get(&key), None);
+        map.insert(key, i).unwrap();
+
+        for j in 0..=i {
+            assert_eq!(map.get(&(-(j as i16) * 7)), Some(&j));
+        }
+        assert!(!map.contains_key(&1));
+    }
+
+    assert_eq!(map.insert(-14, 0).unwrap(), Some(2));
+    assert_eq!(map.remove(&-21), Some(3));
+    assert_eq!(map.get(&-21), None);
+    assert_eq!(map.get(&-28), Some(&4));
+}
+
+#[test]
+fn stack_map_char_keys() {
+    let mut map = StackMap::<char, usize, 9>::new();
+    for (i, c) in "linear🦀ß".chars().enumerate() {
+        map.insert(c, i).unwrap();
+    }
+
+    assert_eq!(map.get(&'🦀'), Some(&6));
+    assert_eq!(map.get(&'ß'), Some(&7));
+    assert_eq!(map.get(&'x'), None);
+    assert_eq!(map.entry('r').or_try_insert(0).ok(), Some(&mut 5));
+}
+
+#[test]
+fn stack_map_non_primitive_keys() {
+    let mut map = StackMap::<String, usize, 4>::new();
+    map.insert("a".to_string(), 1).unwrap();
+    map.insert("b".to_string(), 2).unwrap();
+
+    assert_eq!(map.get("b"), Some(&2));
+    assert_eq!(map.get(&"b".to_string()), Some(&2));
+    assert_eq!(map.get("c"), None);
+}
+
+///Checks every present key, plus keys just outside the map, of an `ArrayMap` searched without returning early.
+macro_rules! check_array_map {
+    ($key:ty, $($n:literal),*) => {$({
+        //SAFETY: the keys are distinct.
+        let mut map = unsafe {
+            ArrayMap::<$key, usize, $n>::from_array_unchecked(std::array::from_fn(|i| ((i * 3) as $key, i)))
+        };
+
+        for i in 0..$n {
+            assert_eq!(map.get(&((i * 3) as $key)), Some(&i));
+            assert_eq!(LinearMapView::get(&map, &((i * 3) as $key)), Some(&i));
+            assert_eq!(map.get(&((i * 3 + 1) as $key)), None);
+            *map.get_mut(&((i * 3) as $key)).unwrap() += 1;
+            assert_eq!(map.get(&((i * 3) as $key)), Some(&(i + 1)));
+        }
+        assert_eq!(map.get(&(($n * 3) as $key)), None);
+    })*};
+}
+
+//zero length maps expand to empty ranges.
+#[allow(clippy::reversed_empty_ranges)]
+#[test]
+fn array_map_primitive_keys() {
+    check_array_map!(u8, 0, 1, 15, 16, 17, 33);
+    check_array_map!(i16, 7, 8, 9, 20);
+    check_array_map!(u32, 3, 4, 5, 31);
+    check_array_map!(i64, 1, 2, 3);
+    check_array_map!(usize, 0, 7);
+}
+
+#[test]
+fn array_map_finds_first_of_repeated_keys() {
+    //SAFETY: not memory unsafe, the repeated keys only shadow each other.
+    let map = unsafe {
+        ArrayMap::from_array_unchecked([(1u32, 0), (7, 1), (7, 2), (3, 3), (7, 4), (9, 5), (7, 6)])
+    };
+
+    assert_eq!(map.get(&7), Some(&1));
+    assert_eq!(map.get(&9), Some(&5));
+}
+
+#[test]
+fn contiguous_maps_primitive_keys() {
+    let mut vec = VecMap::<u16, usize>::new();
+    //spills onto the heap part way through every chunk of keys.
+    let mut fat = FatMap::<u16, usize, 5>::new();
+    let mut deque = DequeMap::<u16, usize>::new();
+
+    for i in 0..20 {
+        vec.insert(i as u16 * 3, i).unwrap();
+        fat.insert(i as u16 * 3, i).unwrap();
+        deque.insert(i as u16 * 3, i).unwrap();
+    }
+
+    //wrap the deque around the end of its buffer, so it is searched as two slices.
+    for i in 0..10 {
+        assert_eq!(deque.remove(&(i as u16 * 3)), Some(i));
+        deque.insert(i as u16 * 3, i).unwrap();
+    }
+
+    for i in 0..20 {
+        let key = i as u16 * 3;
+        assert_eq!(vec.get(&key), Some(&i));
+        assert_eq!(fat.get(&key), Some(&i));
+        assert_eq!(deque.get(&key), Some(&i));
+        assert_eq!(vec.get(&(key + 1)), None);
+        assert_eq!(fat.get(&(key + 1)), None);
+        assert_eq!(deque.get(&(key + 1)), None);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    FallibleLinearMap, LinearMapValuesMut, LinearMapView, MoveToFront, SelfOrganizingDequeMap,
+    SelfOrganizingVecMap, Transpose, TryFromIterator,
+};
+
+fn keys<M: FallibleLinearMap<i32, i32>>(map: &M) -> Vec<i32> {
+    map.keys().copied().collect()
+}
+
+const PAIRS: [(i32, i32); 4] = [(1, 10), (2, 20), (3, 30), (4, 40)];
+
+#[test]
+fn move_to_front_promotes_hits() {
+    let mut vec = SelfOrganizingVecMap::<i32, i32, MoveToFront>::try_from_iter(PAIRS).unwrap();
+    let mut deque = SelfOrganizingDequeMap::<i32, i32, MoveToFront>::try_from_iter(PAIRS).unwrap();
+
+    assert_eq!(vec.get_and_promote(&3), Some(&30));
+    assert_eq!(deque.get_and_promote(&3), Some(&30));
+    assert_eq!(keys(&vec), vec![3, 1, 2, 4]);
+    assert_eq!(keys(&deque), vec![3, 1, 2, 4]);
+
+    *vec.get_mut_and_promote(&4).unwrap() += 1;
+    *deque.get_mut_and_promote(&4).unwrap() += 1;
+    assert_eq!(keys(&vec), vec![4, 3, 1, 2]);
+    assert_eq!(keys(&deque), vec![4, 3, 1, 2]);
+    assert_eq!(vec.get(&4), Some(&41));
+    assert_eq!(deque.get(&4), Some(&41));
+}
+
+#[test]
+fn transpose_promotes_hits() {
+    let mut vec = SelfOrganizingVecMap::<i32, i32, Transpose>::try_from_iter(PAIRS).unwrap();
+    let mut deque = SelfOrganizingDequeMap::<i32, i32, Transpose>::try_from_iter(PAIRS).unwrap();
+
+    for _ in 0..2 {
+        vec.get_and_promote(&4);
+        deque.get_and_promote(&4);
+    }
+    assert_eq!(keys(&vec), vec![1, 4, 2, 3]);
+    assert_eq!(keys(&deque), vec![1, 4, 2, 3]);
+
+    //already at the front.
+    *vec.get_mut_and_promote(&1).unwrap() += 1;
+    *deque.get_mut_and_promote(&1).unwrap() += 1;
+    assert_eq!(keys(&vec), vec![1, 4, 2, 3]);
+    assert_eq!(keys(&deque), vec![1, 4, 2, 3]);
+}
+
+#[test]
+fn plain_lookups_do_not_reorder() {
+    let mut vec = SelfOrganizingVecMap::<i32, i32>::try_from_iter(PAIRS).unwrap();
+    let mut deque = SelfOrganizingDequeMap::<i32, i32>::try_from_iter(PAIRS).unwrap();
+
+    assert_eq!(vec.get(&4), Some(&40));
+    assert_eq!(deque.get(&4), Some(&40));
+    assert!(vec.contains_key(&3));
+    assert!(deque.contains_key(&3));
+    assert_eq!(vec.insert(2, 21).unwrap(), Some(20));
+    assert_eq!(deque.insert(2, 21).unwrap(), Some(20));
+    assert_eq!(vec.get_mut(&4), Some(&mut 40));
+    assert_eq!(deque.get_mut(&4), Some(&mut 40));
+
+    assert_eq!(keys(&vec), vec![1, 2, 3, 4]);
+    assert_eq!(keys(&deque), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn misses_do_not_reorder() {
+    let mut vec = SelfOrganizingVecMap::<i32, i32>::try_from_iter(PAIRS).unwrap();
+    let mut deque = SelfOrganizingDequeMap::<i32, i32, Transpose>::try_from_iter(PAIRS).unwrap();
+
+    assert_eq!(vec.get_and_promote(&5), None);
+    assert_eq!(deque.get_mut_and_promote(&5), None);
+    assert_eq!(vec.remove(&2), Some(20));
+    assert_eq!(deque.remove(&2), Some(20));
+
+    assert_eq!(keys(&vec), vec![1, 3, 4]);
+    assert_eq!(keys(&deque), vec![1, 3, 4]);
+}
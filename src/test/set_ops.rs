@@ -0,0 +1,103 @@
+use crate::{stack_list::error::PushError, DequeSet, FallibleLinearSet, FatSet, StackSet, VecSet};
+
+fn vec_set(values: &[i32]) -> VecSet<i32> {
+    let mut set = VecSet::new();
+    for v in values {
+        set.insert(*v).unwrap();
+    }
+    set
+}
+
+fn stack_set<const N: usize>(values: &[i32]) -> StackSet<i32, N> {
+    let mut set = StackSet::new();
+    for v in values {
+        set.insert(*v).unwrap();
+    }
+    set
+}
+
+#[test]
+fn union_across_set_types() {
+    let a = vec_set(&[1, 2, 3]);
+    let b = stack_set::<4>(&[3, 4, 1]);
+
+    assert_eq!(a.union(&b).copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    assert_eq!(b.union(&a).copied().collect::<Vec<_>>(), vec![3, 4, 1, 2]);
+}
+
+#[test]
+fn intersection_keeps_order_of_self() {
+    let a = vec_set(&[1, 2, 3, 4]);
+    let b = stack_set::<3>(&[4, 2, 9]);
+
+    assert_eq!(a.intersection(&b).copied().collect::<Vec<_>>(), vec![2, 4]);
+    assert_eq!(b.intersection(&a).copied().collect::<Vec<_>>(), vec![4, 2]);
+}
+
+#[test]
+fn difference_and_symmetric_difference() {
+    let a = vec_set(&[1, 2, 3]);
+    let mut b: DequeSet<i32> = DequeSet::new();
+    b.insert(2).unwrap();
+    b.insert(5).unwrap();
+
+    assert_eq!(a.difference(&b).copied().collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(b.difference(&a).copied().collect::<Vec<_>>(), vec![5]);
+    assert_eq!(
+        a.symmetric_difference(&b).copied().collect::<Vec<_>>(),
+        vec![1, 3, 5]
+    );
+}
+
+#[test]
+fn subset_superset_disjoint() {
+    let small = stack_set::<2>(&[2, 1]);
+    let big = vec_set(&[1, 2, 3]);
+    let other = vec_set(&[7, 8]);
+    let empty = vec_set(&[]);
+
+    assert!(small.is_subset(&big));
+    assert!(!big.is_subset(&small));
+    assert!(big.is_superset(&small));
+    assert!(empty.is_subset(&small));
+    assert!(big.is_disjoint(&other));
+    assert!(!big.is_disjoint(&small));
+    assert!(empty.is_disjoint(&empty));
+}
+
+#[test]
+fn try_union_with_inserts_missing() {
+    let mut a: FatSet<i32, 2> = FatSet::new();
+    a.insert(1).unwrap();
+    let b = vec_set(&[1, 2, 3]);
+
+    a.try_union_with(&b).unwrap();
+
+    assert_eq!(a.values().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn try_union_with_full_stack_set_is_unchanged() {
+    let mut a = stack_set::<3>(&[1, 2]);
+    let b = vec_set(&[2, 3, 4]);
+
+    assert!(matches!(
+        a.try_union_with(&b),
+        Err(PushError::WouldExceedCapacity)
+    ));
+    assert_eq!(a.values().copied().collect::<Vec<_>>(), vec![1, 2]);
+
+    let c = vec_set(&[1, 3]);
+    a.try_union_with(&c).unwrap();
+    assert_eq!(a.values().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn retain_intersection_removes_others() {
+    let mut a = vec_set(&[1, 2, 3, 4, 5]);
+    let b = stack_set::<3>(&[5, 1, 3]);
+
+    a.retain_intersection(&b);
+
+    assert_eq!(a.values().copied().collect::<Vec<_>>(), vec![1, 3, 5]);
+}
@@ -0,0 +1,3 @@
+pub fn main() {
+    linear_collections_macros::array_set![0, 0, 1, 2, 1];
+}
@@ -0,0 +1,93 @@
+use crate::{
+    stack_list::error::PushError, FallibleLinearMap, LinearMapValuesMut, LinearMapView, SoaFatMap,
+    SoaStackMap, SoaVecMap, TryClone, VecMap,
+};
+
+///Runs the same inserts, updates and removals against a `VecMap` and the provided map, checking they agree at every step.
+fn matches_vec_map<M: FallibleLinearMap<u32, String>>(mut map: M) {
+    let mut expected = VecMap::new();
+
+    for i in 0..40u32 {
+        let key = (i * 7) % 23;
+        assert_eq!(
+            map.insert(key, i.to_string()).unwrap(),
+            expected.insert(key, i.to_string()).unwrap()
+        );
+
+        if i % 5 == 0 {
+            assert_eq!(map.remove(&(i % 23)), expected.remove(&(i % 23)));
+        }
+        if let Some(v) = map.get_mut(&3) {
+            v.push('!');
+            expected.get_mut(&3).unwrap().push('!');
+        }
+
+        assert!(map.iter().eq(expected.iter()));
+    }
+
+    for key in 0..30 {
+        assert_eq!(map.get(&key), expected.get(&key));
+        assert_eq!(map.contains_key(&key), expected.contains_key(&key));
+    }
+    assert_eq!(map.len(), expected.len());
+    assert_eq!(map.nth_key(4), expected.nth_key(4));
+    assert!(map.contains_value(&"39".to_string()));
+}
+
+#[test]
+fn soa_maps_match_vec_map() {
+    matches_vec_map(SoaVecMap::new());
+    matches_vec_map(SoaStackMap::<_, _, 23>::new());
+    //pairs move across the stack and heap boundary.
+    matches_vec_map(SoaFatMap::<_, _, 5>::new());
+}
+
+#[test]
+fn soa_stack_map_full() {
+    let mut map = SoaStackMap::<u8, u8, 2>::new();
+    map.insert(0, 0).unwrap();
+    map.insert(1, 1).unwrap();
+
+    assert!(matches!(
+        map.insert(2, 2),
+        Err(PushError::WouldExceedCapacity)
+    ));
+    assert_eq!(map.insert(1, 3).ok(), Some(Some(1)));
+    assert_eq!(map.keys_slice(), &[0, 1]);
+    assert_eq!(map.values_slice(), &[0, 3]);
+}
+
+#[test]
+fn soa_entry_and_borrowed_keys() {
+    let mut map = SoaVecMap::<String, Vec<u8>>::new();
+
+    map.entry("a".to_string())
+        .or_try_insert(Vec::new())
+        .unwrap()
+        .push(1);
+    map.entry("a".to_string())
+        .or_try_insert(Vec::new())
+        .unwrap()
+        .push(2);
+    map.insert_ref("b", vec![3]).unwrap();
+
+    assert_eq!(map.get("a"), Some(&vec![1, 2]));
+    assert_eq!(map.remove_entry("b"), Some(("b".to_string(), vec![3])));
+
+    let (keys, values) = map.try_clone().unwrap().into_inner();
+    assert_eq!(keys, vec!["a".to_string()]);
+    assert_eq!(values, vec![vec![1, 2]]);
+}
+
+#[test]
+fn soa_fat_map_searches_stack_and_heap() {
+    let mut map = SoaFatMap::<char, usize, 4>::new();
+    for (i, c) in "linear🦀collections".chars().enumerate() {
+        map.insert(c, i).unwrap();
+    }
+
+    assert_eq!(map.get(&'l'), Some(&10));
+    assert_eq!(map.get(&'🦀'), Some(&6));
+    assert_eq!(map.get(&'s'), Some(&17));
+    assert_eq!(map.get(&'x'), None);
+}
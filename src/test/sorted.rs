@@ -0,0 +1,179 @@
+use std::ops::Bound;
+
+use crate::{
+    stack_list::error::PushError, FallibleLinearMap, SortedFatMap, SortedLinearMap, SortedStackMap,
+    SortedVecMap, TryFromIterator,
+};
+
+const KEYS: [i32; 8] = [5, 1, 7, 3, 8, 2, 6, 4];
+
+fn keys<M: FallibleLinearMap<i32, i32>>(map: &M) -> Vec<i32> {
+    map.keys().copied().collect()
+}
+
+fn inserts_in_order<M: SortedLinearMap<i32, i32>>(mut map: M) -> M {
+    for k in KEYS {
+        assert_eq!(map.insert(k, k * 10).unwrap(), None);
+    }
+    assert_eq!(map.insert(3, 33).unwrap(), Some(30));
+
+    assert_eq!(keys(&map), vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    map
+}
+
+#[test]
+fn sorted_maps_insert_in_order() {
+    inserts_in_order(SortedVecMap::new());
+    inserts_in_order(SortedStackMap::<i32, i32, 8>::new());
+    //insertions land on both sides of the stack and heap boundary.
+    inserts_in_order(SortedFatMap::<i32, i32, 3>::new());
+}
+
+#[test]
+fn sorted_lookups_binary_search() {
+    let mut map = inserts_in_order(SortedFatMap::<i32, i32, 3>::new());
+
+    assert_eq!(map.get(&3), Some(&33));
+    assert_eq!(map.get(&9), None);
+    assert!(map.contains_key(&8));
+    *map.get_mut(&1).unwrap() += 1;
+    assert_eq!(map.remove(&1), Some(11));
+    assert_eq!(map.remove_entry(&4), Some((4, 40)));
+    assert_eq!(map.remove(&4), None);
+
+    assert_eq!(keys(&map), vec![2, 3, 5, 6, 7, 8]);
+}
+
+#[test]
+fn sorted_borrowed_lookups() {
+    let map = SortedVecMap::try_from_iter([("b".to_string(), 2), ("a".to_string(), 1)]).unwrap();
+
+    assert_eq!(map.get("a"), Some(&1));
+    assert_eq!(map.first().map(|(k, _)| k.as_str()), Some("a"));
+    assert_eq!(
+        map.range::<str, _>((Bound::Included("b"), Bound::Unbounded))
+            .map(|(_, v)| *v)
+            .collect::<Vec<_>>(),
+        vec![2]
+    );
+}
+
+#[test]
+fn sorted_first_last_lower_bound() {
+    let map = SortedStackMap::<i32, i32, 4>::try_from_iter([(10, 1), (30, 3), (20, 2)]).unwrap();
+
+    assert_eq!(map.first(), Some((&10, &1)));
+    assert_eq!(map.last(), Some((&30, &3)));
+    assert_eq!(map.lower_bound(&15), Some((&20, &2)));
+    assert_eq!(map.lower_bound(&20), Some((&20, &2)));
+    assert_eq!(map.lower_bound(&31), None);
+
+    let empty = SortedVecMap::<i32, i32>::new();
+    assert_eq!(empty.first(), None);
+    assert_eq!(empty.last(), None);
+}
+
+#[test]
+fn sorted_range() {
+    let map = inserts_in_order(SortedVecMap::new());
+    let range =
+        |r: std::ops::RangeInclusive<i32>| map.range(r).map(|(k, _)| *k).collect::<Vec<_>>();
+
+    assert_eq!(range(3..=5), vec![3, 4, 5]);
+    assert_eq!(range(0..=1), vec![1]);
+    assert_eq!(range(9..=12), Vec::<i32>::new());
+    assert_eq!(
+        map.range(6..).map(|(k, _)| *k).collect::<Vec<_>>(),
+        vec![6, 7, 8]
+    );
+    assert_eq!(
+        map.range(..3).map(|(k, _)| *k).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let inverted = map.range(5..2).count();
+    assert_eq!(inverted, 0);
+}
+
+#[test]
+fn sorted_entry_keeps_order() {
+    let mut map = SortedFatMap::<i32, i32, 2>::new();
+    for k in [4, 2, 3] {
+        *map.entry(k).or_try_insert(0).unwrap() += k;
+    }
+    *map.entry(2).or_try_insert(0).unwrap() += 1;
+    map.entry(1).try_insert(1).unwrap();
+
+    assert_eq!(keys(&map), vec![1, 2, 3, 4]);
+    assert_eq!(map.get(&2), Some(&3));
+}
+
+#[test]
+fn sorted_stack_map_full() {
+    let mut map = SortedStackMap::<i32, i32, 2>::new();
+    map.insert(2, 2).unwrap();
+    map.insert(1, 1).unwrap();
+
+    assert!(matches!(
+        map.insert(0, 0),
+        Err(PushError::WouldExceedCapacity)
+    ));
+    assert_eq!(map.insert(1, 10).unwrap(), Some(1));
+    assert_eq!(keys(&map), vec![1, 2]);
+}
+
+thread_local! {
+    static EQ_CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+///A key which counts how often it is compared for equality, to tell a binary search from a linear scan.
+#[derive(PartialOrd, Ord)]
+struct Counted(i32);
+
+impl PartialEq for Counted {
+    fn eq(&self, other: &Self) -> bool {
+        EQ_CALLS.with(|calls| calls.set(calls.get() + 1));
+        self.0 == other.0
+    }
+}
+
+impl Eq for Counted {}
+
+fn trait_entry_binary_searches<M: SortedLinearMap<Counted, i32>>(mut map: M) {
+    for k in 0..32 {
+        map.insert(Counted(k), k).unwrap();
+    }
+
+    EQ_CALLS.with(|calls| calls.set(0));
+    *FallibleLinearMap::entry(&mut map, Counted(31))
+        .or_try_insert(0)
+        .unwrap() += 1;
+    map.entry(Counted(32)).try_insert(32).unwrap();
+
+    //one equality check per lookup, against the key found by the binary search.
+    assert_eq!(EQ_CALLS.with(|calls| calls.get()), 1);
+    assert_eq!(map.len(), 33);
+}
+
+#[test]
+fn sorted_trait_entry_binary_searches() {
+    trait_entry_binary_searches(SortedVecMap::new());
+    trait_entry_binary_searches(SortedStackMap::<Counted, i32, 33>::new());
+    trait_entry_binary_searches(SortedFatMap::<Counted, i32, 8>::new());
+}
+
+#[test]
+fn sorted_index() {
+    let map = inserts_in_order(SortedStackMap::<i32, i32, 8>::new());
+
+    assert_eq!(map[&3], 33);
+    assert_eq!(map[&8], 80);
+}
+
+#[test]
+#[should_panic(expected = "key not found in map")]
+fn sorted_index_missing_key() {
+    let map = inserts_in_order(SortedVecMap::new());
+
+    let _ = map[&9];
+}
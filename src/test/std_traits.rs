@@ -0,0 +1,205 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{
+    array::{map::ArrayMap, set::ArraySet},
+    AdaptiveMap, DequeMap, FallibleLinearMap, FallibleLinearSet, FatMap, FatSet, FingerprintVecMap,
+    LinearMapView, SoaStackMap, SortedVecMap, StackMap, StackSet, TryFromIterator, VecMap, VecSet,
+};
+
+fn pairs() -> impl DoubleEndedIterator<Item = (&'static str, u32)> {
+    [("one", 1), ("two", 2), ("three", 3)].into_iter()
+}
+
+///Iterates `map` by reference, by mutable reference and by value, doubling every value along the way.
+fn iterates<M>(mut map: M)
+where
+    M: FallibleLinearMap<&'static str, u32> + IntoIterator<Item = (&'static str, u32)>,
+    for<'a> &'a M: IntoIterator<Item = (&'a &'static str, &'a u32)>,
+    for<'a> &'a mut M: IntoIterator<Item = (&'a &'static str, &'a mut u32)>,
+{
+    let mut sum = 0;
+    for (_, v) in &map {
+        sum += v;
+    }
+    assert_eq!(sum, 6);
+
+    for (_, v) in &mut map {
+        *v *= 2;
+    }
+
+    let mut owned = map.into_iter().collect::<Vec<_>>();
+    owned.sort();
+    assert_eq!(owned, vec![("one", 2), ("three", 6), ("two", 4)]);
+}
+
+#[test]
+fn every_map_iterates_in_each_form() {
+    iterates(VecMap::try_from_iter(pairs()).unwrap());
+    iterates(DequeMap::try_from_iter(pairs()).unwrap());
+    iterates(SortedVecMap::try_from_iter(pairs()).unwrap());
+    iterates(FatMap::<_, _, 2>::try_from_iter(pairs()).unwrap());
+    iterates(StackMap::<_, _, 3>::try_from_iter(pairs()).unwrap());
+    iterates(SoaStackMap::<_, _, 4>::try_from_iter(pairs()).unwrap());
+    iterates(FingerprintVecMap::<_, _>::try_from_iter(pairs()).unwrap());
+    iterates(AdaptiveMap::<_, _, 2>::try_from_iter(pairs()).unwrap());
+}
+
+#[test]
+fn array_map_iterates_in_each_form() {
+    let mut map = unsafe { ArrayMap::from_array_unchecked([("one", 1), ("two", 2)]) };
+    for (_, v) in &mut map {
+        *v += 10;
+    }
+    assert!((&map).into_iter().map(|(_, v)| *v).eq([11, 12]));
+    assert!(map.into_iter().eq([("one", 11), ("two", 12)]));
+}
+
+#[test]
+fn index_by_borrowed_key() {
+    let map = VecMap::try_from_iter([(String::from("a"), 1), (String::from("b"), 2)]).unwrap();
+    assert_eq!(map["a"], 1);
+    assert_eq!(map[&String::from("b")], 2);
+
+    let fingerprinted = FingerprintVecMap::<_, _>::try_from_iter(pairs()).unwrap();
+    assert_eq!(fingerprinted["three"], 3);
+
+    let adaptive = AdaptiveMap::<_, _, 1>::try_from_iter(pairs()).unwrap();
+    assert!(adaptive.is_promoted());
+    assert_eq!(adaptive["two"], 2);
+}
+
+#[test]
+#[should_panic(expected = "key not found in map")]
+fn index_panics_on_missing_key() {
+    let map = StackMap::<u8, u8, 2>::try_from_iter([(1, 1)]).unwrap();
+    let _ = map[&2];
+}
+
+#[test]
+fn debug_is_shaped_like_a_map() {
+    let map = VecMap::try_from_iter(pairs()).unwrap();
+    assert_eq!(format!("{map:?}"), r#"{"one": 1, "two": 2, "three": 3}"#);
+
+    let sorted = SortedVecMap::try_from_iter(pairs()).unwrap();
+    assert_eq!(format!("{sorted:?}"), r#"{"one": 1, "three": 3, "two": 2}"#);
+
+    let empty = StackMap::<u8, u8, 2>::new();
+    assert_eq!(format!("{empty:?}"), "{}");
+    assert_eq!(LinearMapView::len(&empty), 0);
+}
+
+#[test]
+fn sets_iterate_and_debug_like_sets() {
+    let set = VecSet::try_from_iter([3, 1, 2]).unwrap();
+    assert_eq!(format!("{set:?}"), "{3, 1, 2}");
+    assert!((&set).into_iter().eq(&[3, 1, 2]));
+    assert!(set.into_iter().eq([3, 1, 2]));
+
+    let stack = StackSet::<_, 4>::try_from_iter(["a", "b"]).unwrap();
+    let mut seen = vec![];
+    for v in &stack {
+        seen.push(*v);
+    }
+    assert_eq!(seen, ["a", "b"]);
+    assert_eq!(stack.len(), 2);
+
+    let fat = FatSet::<_, 1>::try_from_iter([1, 2]).unwrap();
+    assert!(fat.into_iter().eq([1, 2]));
+
+    let array = unsafe { ArraySet::from_array_unchecked(['x', 'y']) };
+    assert_eq!(format!("{array:?}"), "{'x', 'y'}");
+    assert!((&array).into_iter().eq(&['x', 'y']));
+    assert!(array.into_iter().eq(['x', 'y']));
+}
+
+fn hash_of<T: Hash>(t: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    t.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn maps_compare_regardless_of_order() {
+    let forward = VecMap::try_from_iter(pairs()).unwrap();
+    let backward = VecMap::try_from_iter(pairs().rev()).unwrap();
+    assert_eq!(forward, backward);
+    assert_eq!(hash_of(&forward), hash_of(&backward));
+    assert!(!forward.eq_ordered(&backward));
+    assert!(forward.eq_ordered(&forward));
+
+    let mut changed = VecMap::try_from_iter(pairs()).unwrap();
+    changed.insert("two", 20).unwrap();
+    assert_ne!(forward, changed);
+    changed.remove(&"two");
+    assert_ne!(forward, changed);
+}
+
+#[test]
+fn maps_compare_across_types() {
+    let vec = VecMap::try_from_iter(pairs()).unwrap();
+    let stack = StackMap::<_, _, 3>::try_from_iter(pairs().rev()).unwrap();
+    let deque = DequeMap::try_from_iter(pairs()).unwrap();
+    let array = unsafe { ArrayMap::from_array_unchecked([("three", 3), ("one", 1), ("two", 2)]) };
+    let adaptive = AdaptiveMap::<_, _, 1>::try_from_iter(pairs()).unwrap();
+
+    assert!(stack == vec);
+    assert!(vec == stack);
+    assert!(deque == array);
+    assert!(adaptive == vec);
+    assert!(vec.eq_ordered(&deque));
+    assert_eq!(hash_of(&vec), hash_of(&stack));
+    assert_eq!(hash_of(&vec), hash_of(&array));
+    assert_eq!(hash_of(&vec), hash_of(&adaptive));
+}
+
+#[test]
+fn sets_compare_regardless_of_order() {
+    let vec = VecSet::try_from_iter([1, 2, 3]).unwrap();
+    let stack = StackSet::<_, 3>::try_from_iter([3, 2, 1]).unwrap();
+    assert!(vec == stack);
+    assert!(!vec.eq_ordered(&stack));
+    assert_eq!(hash_of(&vec), hash_of(&stack));
+    assert_ne!(vec, VecSet::try_from_iter([1, 2]).unwrap());
+
+    let array = unsafe { ArraySet::from_array_unchecked([1, 2, 3]) };
+    let reversed = unsafe { ArraySet::from_array_unchecked([3, 2, 1]) };
+    assert_eq!(array, reversed);
+    assert!(!array.eq_ordered(&reversed));
+    assert_eq!(hash_of(&array), hash_of(&vec));
+    assert!(array != unsafe { ArraySet::from_array_unchecked([1, 2]) });
+}
+
+///Records every integer it is fed, to check what reaches the caller's hasher.
+#[derive(Default)]
+struct Recorder(Vec<u64>);
+
+impl Hasher for Recorder {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, _: &[u8]) {
+        panic!("only integers are written");
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.0.push(i)
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.0.push(i as u64)
+    }
+}
+
+#[test]
+fn item_hashes_are_summed_into_the_callers_hasher() {
+    let set = VecSet::try_from_iter([3, 1, 2]).unwrap();
+    let mut recorder = Recorder::default();
+    set.hash(&mut recorder);
+
+    let sum = [1, 2, 3]
+        .map(|v| hash_of(&v))
+        .into_iter()
+        .fold(0u64, u64::wrapping_add);
+    assert_eq!(recorder.0, [3, sum]);
+}
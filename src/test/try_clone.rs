@@ -0,0 +1,78 @@
+use crate::{
+    stack_list::StackList, DequeMap, DequeSet, FallibleLinearMap, FallibleLinearSet, FatMap,
+    FatSet, FatVec, LinearMapView, StackMap, StackSet, TryClone, TryFromIterator, VecMap, VecSet,
+};
+
+fn pairs(len: i32) -> impl Iterator<Item = (i32, String)> {
+    (0..len).map(|i| (i, i.to_string()))
+}
+
+#[test]
+fn heap_maps_try_clone() {
+    let vec_map = VecMap::try_from_iter(pairs(4)).unwrap();
+    let deque_map = DequeMap::try_from_iter(pairs(4)).unwrap();
+    //spans both the stack and the heap.
+    let fat_map: FatMap<i32, String, 2> = FatMap::try_from_iter(pairs(4)).unwrap();
+
+    assert_eq!(vec_map.try_clone().unwrap(), vec_map);
+    assert_eq!(
+        deque_map.try_clone().unwrap().iter().collect::<Vec<_>>(),
+        deque_map.iter().collect::<Vec<_>>()
+    );
+    assert_eq!(fat_map.try_clone().unwrap(), fat_map);
+}
+
+#[test]
+fn try_clone_is_independent() {
+    let mut original: FatMap<i32, String, 1> = FatMap::try_from_iter(pairs(3)).unwrap();
+    let copy = original.try_clone().unwrap();
+
+    original.insert(0, "changed".to_string()).unwrap();
+    original.remove(&2);
+
+    assert_eq!(copy.get(&0).map(String::as_str), Some("0"));
+    assert_eq!(copy.len(), 3);
+}
+
+#[test]
+fn try_clone_from_reuses_target() {
+    let source = VecMap::try_from_iter(pairs(2)).unwrap();
+    let mut target = VecMap::try_from_iter(pairs(5)).unwrap();
+
+    target.try_clone_from(&source).unwrap();
+    assert_eq!(target, source);
+
+    let mut fat: FatVec<i32, 2> = FatVec::try_from_iter(0..5).unwrap();
+    fat.try_clone_from(&FatVec::try_from_iter(0..1).unwrap())
+        .unwrap();
+    assert_eq!(fat.iter().copied().collect::<Vec<_>>(), vec![0]);
+}
+
+#[test]
+fn sets_try_clone() {
+    let vec_set = VecSet::try_from_iter(0..3).unwrap();
+    let deque_set = DequeSet::try_from_iter(0..3).unwrap();
+    let fat_set: FatSet<i32, 1> = FatSet::try_from_iter(0..3).unwrap();
+    let stack_set: StackSet<i32, 3> = StackSet::try_from_iter(0..3).unwrap();
+
+    assert_eq!(vec_set.try_clone().unwrap(), vec_set);
+    assert!(deque_set.try_clone().unwrap().is_subset(&deque_set));
+    assert_eq!(fat_set.try_clone().unwrap(), fat_set);
+    assert_eq!(stack_set.clone().len(), 3);
+}
+
+#[test]
+fn stack_collections_clone() {
+    let list: StackList<String, 4> = StackList::try_from_iter(["a".to_string()]).unwrap();
+    let map: StackMap<i32, String, 4> = StackMap::try_from_iter(pairs(3)).unwrap();
+
+    assert_eq!(list.clone(), list);
+    assert_eq!(
+        map.clone().iter().collect::<Vec<_>>(),
+        map.iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        map.try_clone().unwrap().get(&2).map(String::as_str),
+        Some("2")
+    );
+}
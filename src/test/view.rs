@@ -0,0 +1,54 @@
+use crate::{
+    array::map::ArrayMap, FatMap, LinearMapValuesMut, LinearMapView, SortedVecMap, StackMap,
+    TryFromIterator, VecMap,
+};
+
+fn pairs() -> [(&'static str, u32); 3] {
+    [("one", 1), ("two", 2), ("three", 3)]
+}
+
+///Only reads the map, so accepts every map, including `ArrayMap`.
+fn reads<M: LinearMapView<&'static str, u32>>(map: &M) {
+    assert_eq!(map.len(), 3);
+    assert!(!map.is_empty());
+    assert_eq!(map.get("two"), Some(&2));
+    assert_eq!(map.get("four"), None);
+    assert!(map.contains_key("three"));
+    assert!(map.contains_value(&1));
+    assert_eq!(map.values().sum::<u32>(), 6);
+
+    let mut keys = map.keys().copied().collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(keys, vec!["one", "three", "two"]);
+    assert_eq!(map.nth_key(3), None);
+}
+
+fn doubles<M: LinearMapValuesMut<&'static str, u32>>(map: &mut M) {
+    map.values_mut().for_each(|v| *v *= 2);
+    *map.get_mut("one").unwrap() += 1;
+    map.replace("two", 40);
+    //replacing a missing key does nothing.
+    map.replace("four", 4);
+}
+
+#[test]
+fn every_map_is_viewable() {
+    reads(&unsafe { ArrayMap::from_array_unchecked(pairs()) });
+    reads(&VecMap::try_from_iter(pairs()).unwrap());
+    reads(&StackMap::<_, _, 3>::try_from_iter(pairs()).unwrap());
+    reads(&FatMap::<_, _, 2>::try_from_iter(pairs()).unwrap());
+    reads(&SortedVecMap::try_from_iter(pairs()).unwrap());
+}
+
+#[test]
+fn array_map_values_mut() {
+    let mut array = unsafe { ArrayMap::from_array_unchecked(pairs()) };
+    let mut vec = VecMap::try_from_iter(pairs()).unwrap();
+
+    doubles(&mut array);
+    doubles(&mut vec);
+
+    assert!(LinearMapView::iter(&array).eq(LinearMapView::iter(&vec)));
+    assert_eq!(array.as_slice(), &[("one", 3), ("two", 40), ("three", 6)]);
+    assert_eq!(array.nth_value_mut(2), Some(&mut 6));
+}
@@ -0,0 +1,71 @@
+use crate::{
+    stack_list::error::PushError, DequeMap, FallibleLinearMap, FatMap, LinearMapView, StackMap,
+    VecMap,
+};
+
+#[test]
+fn window_inserts_new_keys() {
+    let mut m: VecMap<i32, i32> = VecMap::new();
+
+    let mut window = m.try_reserve_window::<4>().unwrap();
+    for i in 0..4 {
+        assert_eq!(window.insert(i, i * 10), None);
+    }
+    assert_eq!(window.remaining_slots(), 0);
+
+    assert_eq!(m.len(), 4);
+    assert_eq!(m.get(&3), Some(&30));
+}
+
+#[test]
+fn window_updates_do_not_consume_slots() {
+    let mut m: DequeMap<&str, i32> = DequeMap::new();
+    m.insert("a", 1).unwrap();
+
+    let mut window = m.try_reserve_window::<1>().unwrap();
+    assert_eq!(window.insert("a", 2), Some(1));
+    assert_eq!(window.insert("a", 3), Some(2));
+    assert_eq!(window.remaining_slots(), 1);
+    assert_eq!(window.insert("b", 1), None);
+    assert_eq!(window.map().get(&"a"), Some(&3));
+}
+
+#[test]
+fn window_try_insert_returns_pair_when_exhausted() {
+    let mut m: FatMap<i32, i32, 1> = FatMap::new();
+
+    let mut window = m.try_reserve_window::<2>().unwrap();
+    assert_eq!(window.try_insert(1, 1), Ok(None));
+    assert_eq!(window.try_insert(2, 2), Ok(None));
+    assert_eq!(window.try_insert(3, 3), Err((3, 3)));
+    assert_eq!(window.try_insert(2, 20), Ok(Some(2)));
+
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn window_insert_panics_when_exhausted() {
+    let mut m: VecMap<i32, i32> = VecMap::new();
+
+    let mut window = m.try_reserve_window::<1>().unwrap();
+    window.insert(1, 1);
+    window.insert(2, 2);
+}
+
+#[test]
+fn window_stack_map_checks_capacity() {
+    let mut m: StackMap<i32, i32, 3> = StackMap::new();
+    m.insert(0, 0).unwrap();
+
+    assert!(matches!(
+        m.try_reserve_window::<3>(),
+        Err(PushError::WouldExceedCapacity)
+    ));
+
+    let mut window = m.try_reserve_window::<2>().unwrap();
+    window.insert(1, 1);
+    window.insert(2, 2);
+
+    assert_eq!(m.len(), 3);
+}
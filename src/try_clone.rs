@@ -0,0 +1,50 @@
+use std::collections::{TryReserveError, VecDeque};
+
+///Fallible counterpart of `Clone` for collections which allocate.
+///`Clone` panics or aborts if the allocation for the copy fails, so the heap backed collections in this crate only implement this.
+pub trait TryClone: Sized {
+    ///Returns a copy of self, or an error if the copy could not be allocated.
+    fn try_clone(&self) -> Result<Self, TryReserveError>;
+
+    ///Performs copy-assignment from `source`, reusing the allocation of self where possible.
+    ///If this returns an error self is left unchanged.
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        *self = source.try_clone()?;
+        Ok(())
+    }
+}
+
+impl<T: Clone> TryClone for Vec<T> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        let mut vec = Vec::try_with_capacity(self.len())?;
+        //we have room for every element, so this will not reallocate.
+        vec.extend(self.iter().cloned());
+        Ok(vec)
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        //capacity is reserved relative to our current length, so this makes room for all of `source`.
+        self.try_reserve_exact(source.len().saturating_sub(self.len()))?;
+        self.clear();
+        self.extend(source.iter().cloned());
+        Ok(())
+    }
+}
+
+impl<T: Clone> TryClone for VecDeque<T> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        let mut deque = VecDeque::new();
+        deque.try_reserve_exact(self.len())?;
+        //we have room for every element, so this will not reallocate.
+        deque.extend(self.iter().cloned());
+        Ok(deque)
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        //capacity is reserved relative to our current length, so this makes room for all of `source`.
+        self.try_reserve_exact(source.len().saturating_sub(self.len()))?;
+        self.clear();
+        self.extend(source.iter().cloned());
+        Ok(())
+    }
+}
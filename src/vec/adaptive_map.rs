@@ -0,0 +1,532 @@
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, TryReserveError},
+    hash::{BuildHasher, Hash, RandomState},
+    ptr,
+};
+
+use crate::{
+    entry::{Entry, OccupiedEntry, VacantEntry},
+    FallibleLinearMap, LinearMapValuesMut, LinearMapView, MapExtract, MapIndex, MapIndexMut,
+    MapIterMut, TryClone, TryExtendError, TryFromIterator, VecMap,
+};
+
+///The store backing an `AdaptiveMap`, returned by `into_inner`.
+#[derive(Debug)]
+pub enum AdaptiveBacking<K, V, S = RandomState> {
+    ///The map held no more than `THRESHOLD` pairs, in insertion order.
+    Linear(Vec<(K, V)>),
+    ///The map had been promoted to a hash table.
+    Hashed(HashMap<K, V, S>),
+}
+
+///A map which starts out backed by a Vector, like `VecMap`, and promotes itself to a `std::collections::HashMap` once it
+///holds more than `THRESHOLD` pairs. Useful for maps which are nearly always small, but which occasionally grow large enough
+///for a linear scan to become the bottleneck.
+///
+///Once promoted, the map is demoted back to a Vector when removing a key leaves it with fewer than `LOW_WATERMARK` pairs. The gap between
+///the two keeps a map hovering around the threshold from moving its pairs back and forth on every insertion.
+///`retain`, `extract_if` and `drain` hold a borrow of the table while they remove pairs, so leave the map promoted until the next removal by key.
+///Both moves are fallible: if the new store cannot be allocated the map simply stays as it is, and tries again later.
+///
+///Pairs are kept in insertion order while the map is linear, but are in no particular order once it has been promoted.
+///As with `FingerprintVecMap`, only the inherent lookups, like `get` and `remove`, and `insert` and `entry` hash the key.
+///The other methods of the map traits can't require the borrowed form of the key to be `Hash`, and scan the hash table instead.
+///Positional access walks the table too, including every step of an `Entry`, so large maps are best used through `insert` and the inherent lookups.
+pub struct AdaptiveMap<K: Eq, V, const THRESHOLD: usize, S = RandomState> {
+    ///Holds every pair until the map is promoted, after which it is always empty.
+    linear: VecMap<K, V>,
+    ///Holds every pair once the map is promoted. Its table is only allocated while the map is promoted.
+    hashed: HashMap<K, V, S>,
+    ///Whether the pairs live in `hashed` rather than `linear`.
+    promoted: bool,
+}
+
+impl<K: Eq, V, const THRESHOLD: usize> AdaptiveMap<K, V, THRESHOLD, RandomState> {
+    ///Creates a new, empty AdaptiveMap.
+    ///Neither store allocates until the first insertion.
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize> AdaptiveMap<K, V, THRESHOLD, RandomState> {
+    ///Creates a new, empty AdaptiveMap with room for `capacity` pairs.
+    ///If `capacity` exceeds `THRESHOLD` the map is promoted up front.
+    pub fn with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K: Eq, V, const THRESHOLD: usize, S> AdaptiveMap<K, V, THRESHOLD, S> {
+    ///Once promoted, the map is demoted back to a Vector when it holds fewer pairs than this.
+    pub const LOW_WATERMARK: usize = THRESHOLD / 2;
+
+    ///Creates a new, empty AdaptiveMap which will hash keys with `hasher` once it is promoted.
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            linear: VecMap::new(),
+            hashed: HashMap::with_hasher(hasher),
+            promoted: false,
+        }
+    }
+
+    ///Returns true if the map is currently backed by a hash table rather than a Vector.
+    pub fn is_promoted(&self) -> bool {
+        self.promoted
+    }
+
+    ///Returns a reference to the map's `BuildHasher`.
+    pub fn hasher(&self) -> &S {
+        self.hashed.hasher()
+    }
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher> AdaptiveMap<K, V, THRESHOLD, S> {
+    ///Creates a new, empty AdaptiveMap with room for `capacity` pairs, which will hash keys with `hasher` once it is promoted.
+    ///If `capacity` exceeds `THRESHOLD` the map is promoted up front.
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Result<Self, TryReserveError> {
+        let mut map = Self::with_hasher(hasher);
+        map.reserve(capacity)?;
+        Ok(map)
+    }
+
+    ///Moves every pair into the hash table, leaving room for `additional` more.
+    ///The map is left linear if the table could not be allocated.
+    fn promote(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.hashed
+            .try_reserve(self.linear.len().saturating_add(additional))?;
+
+        //no insertion can allocate, as room was reserved for every pair.
+        self.hashed
+            .extend(std::mem::take(&mut self.linear).into_inner());
+        self.promoted = true;
+        Ok(())
+    }
+
+    ///Moves every pair back into a Vector if the map is promoted and has shrunk below `LOW_WATERMARK`.
+    ///The map is left promoted if the Vector could not be allocated.
+    fn demote_if_small(&mut self) {
+        if !self.is_promoted() || self.hashed.len() >= Self::LOW_WATERMARK {
+            return;
+        }
+
+        let Ok(mut pairs) = Vec::try_with_capacity(self.hashed.len()) else {
+            return;
+        };
+        //`drain` is exactly sized, so this cannot allocate.
+        pairs.extend(self.hashed.drain());
+        //frees the table, as it is now empty.
+        self.hashed.shrink_to_fit();
+
+        //SAFETY: the keys came out of a map, so are unique.
+        self.linear = unsafe { VecMap::from_vec_unchecked(pairs) };
+        self.promoted = false;
+    }
+
+    ///Adds a pair whose key is not already in the map, promoting the map first if it is full.
+    ///Returns the address of the key in its new home, so that `push_unchecked` can work out its index.
+    fn push_new(&mut self, key: K, value: V) -> Result<*const K, TryReserveError> {
+        //a failed promotion falls back to pushing onto the Vector.
+        match self.is_promoted() || (self.linear.len() >= THRESHOLD && self.promote(1).is_ok()) {
+            false => {
+                let index = self.linear.push_unchecked(key, value)?;
+                //SAFETY: `push_unchecked` returns the index of the pair it just added.
+                Ok(unsafe { self.linear.get_index_unchecked(index) }.0)
+            }
+            true => {
+                self.hashed.try_reserve(1)?;
+                Ok(self.hashed.entry(key).insert_entry(value).key())
+            }
+        }
+    }
+
+    ///Returns the index of the pair whose key is equal to `key`.
+    ///Once promoted, the table finds the key in constant time, leaving only a cheap scan for its address to work out the index.
+    fn find<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        match self.is_promoted() {
+            false => self.linear.index_of(key),
+            true => {
+                let (found, _) = self.hashed.get_key_value(key)?;
+                self.hashed.keys().position(|k| ptr::eq(k, found))
+            }
+        }
+    }
+
+    ///Gets a reference to the value associated with the key. Will return None if that key is not in the map.
+    ///The key may be any borrowed form of the map's key type, but `Hash` and `Eq` on the borrowed form *must* match those for the key type.
+    pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        match self.is_promoted() {
+            false => self.linear.get(key),
+            true => self.hashed.get(key),
+        }
+    }
+
+    ///Gets a mutable reference to the value associated with the key. Will return None if that key is not in the map.
+    ///The key may be any borrowed form of the map's key type, but `Hash` and `Eq` on the borrowed form *must* match those for the key type.
+    pub fn get_mut<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        match self.is_promoted() {
+            false => self.linear.get_mut(key),
+            true => self.hashed.get_mut(key),
+        }
+    }
+
+    ///Returns true if this map contains the given key. False otherwise.
+    ///The key may be any borrowed form of the map's key type, but `Hash` and `Eq` on the borrowed form *must* match those for the key type.
+    pub fn contains_key<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key).is_some()
+    }
+
+    ///Tries to remove the entry associated with the given key, returning None if it is not found.
+    ///The key may be any borrowed form of the map's key type, but `Hash` and `Eq` on the borrowed form *must* match those for the key type.
+    pub fn remove_entry<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        match self.is_promoted() {
+            false => self.linear.remove_entry(key),
+            true => {
+                let pair = self.hashed.remove_entry(key);
+                self.demote_if_small();
+                pair
+            }
+        }
+    }
+
+    ///Tries to remove the value associated with the given key, returning None if it is not found.
+    ///The key may be any borrowed form of the map's key type, but `Hash` and `Eq` on the borrowed form *must* match those for the key type.
+    pub fn remove<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher> FallibleLinearMap<K, V>
+    for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    type Backing = AdaptiveBacking<K, V, S>;
+    type InsertionError = TryReserveError;
+
+    fn into_inner(self) -> Self::Backing {
+        match self.is_promoted() {
+            false => AdaptiveBacking::Linear(self.linear.into_inner()),
+            true => AdaptiveBacking::Hashed(self.hashed),
+        }
+    }
+
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        match self.is_promoted() {
+            false => self.linear.remove_entry(key),
+            true => {
+                //stops at the first match, keeping the rest of the pairs.
+                let pair = self.hashed.extract_if(|k, _| k.borrow() == key).next();
+                self.demote_if_small();
+                pair
+            }
+        }
+    }
+
+    ///Inserts the provided value into the map. If the provided key is
+    ///found it will update the value. and return the old value. If not, this will allocate for a new key value pair,
+    ///promoting the map if it already holds `THRESHOLD` pairs.
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError> {
+        match self.get_mut(&key) {
+            Some(v) => Ok(Some(std::mem::replace(v, value))),
+            None => self.push_new(key, value).map(|_| None),
+        }
+    }
+
+    ///Once promoted, the entry reaches its pair by its index in the table's iteration order, so every step walks the table.
+    ///Prefer the inherent lookups and `insert` on large maps.
+    fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V, Self>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        match self.find(&key) {
+            //SAFETY: `find` only returns indices of pairs within the map.
+            Some(index) => Entry::Occupied(unsafe { OccupiedEntry::new(self, index) }),
+            None => Entry::Vacant(VacantEntry::new(self, key)),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher> LinearMapView<K, V>
+    for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        //one of the two is always empty.
+        self.linear.iter().chain(self.hashed.iter())
+    }
+
+    fn len(&self) -> usize {
+        self.linear.len() + self.hashed.len()
+    }
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher> LinearMapValuesMut<K, V>
+    for AdaptiveMap<K, V, THRESHOLD, S>
+{
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher> MapIterMut<K, V>
+    for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        //one of the two is always empty.
+        self.linear.iter_mut().chain(self.hashed.iter_mut())
+    }
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher> MapExtract<K, V>
+    for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        //`pred` can only be handed to one of the two stores, so the other yields nothing.
+        let (linear, hashed) = match self.is_promoted() {
+            false => (Some(self.linear.extract_pairs_if(pred)), None),
+            true => (None, Some(self.hashed.extract_if(pred))),
+        };
+
+        linear
+            .into_iter()
+            .flatten()
+            .chain(hashed.into_iter().flatten())
+    }
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher> MapIndexMut<K, V>
+    for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        match self.is_promoted() {
+            //SAFETY: upheld by caller
+            false => unsafe { self.linear.remove_index_unchecked(index) },
+            true => {
+                //indices follow the iteration order of the table, which `extract_if` visits in, stopping at the first match.
+                let mut position = 0;
+                let pair = self
+                    .hashed
+                    .extract_if(|_, _| {
+                        position += 1;
+                        position - 1 == index
+                    })
+                    .next();
+                self.demote_if_small();
+
+                //SAFETY: upheld by caller
+                unsafe { pair.unwrap_unchecked() }
+            }
+        }
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        let key = self.push_new(key, value)?;
+
+        match self.is_promoted() {
+            false => Ok(self.linear.len() - 1),
+            //SAFETY: `push_new` returns the address of a key in the table.
+            true => Ok(unsafe {
+                self.hashed
+                    .keys()
+                    .position(|k| ptr::eq(k, key))
+                    .unwrap_unchecked()
+            }),
+        }
+    }
+
+    ///Reserving room for more than `THRESHOLD` pairs promotes the map.
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        match self.is_promoted() {
+            true => self.hashed.try_reserve(additional),
+            false => match self.linear.len().saturating_add(additional) > THRESHOLD {
+                true => self
+                    .promote(additional)
+                    .or_else(|_| self.linear.reserve(additional)),
+                false => self.linear.reserve(additional),
+            },
+        }
+    }
+
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.reserve(additional)
+    }
+
+    ///Hashes the key once promoted, rather than going through `entry`.
+    fn insert_if_vacant(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<bool, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        match self.contains_key(&key) {
+            true => Ok(false),
+            false => self.push_new(key, value).map(|_| true),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher> MapIndex<K, V>
+    for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    ///Once promoted, indices follow the iteration order of the hash table, so this is linear in `index`.
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        match self.is_promoted() {
+            //SAFETY: upheld by caller
+            false => unsafe { self.linear.get_index_unchecked(index) },
+            //SAFETY: upheld by caller
+            true => unsafe { self.hashed.iter().nth(index).unwrap_unchecked() },
+        }
+    }
+
+    ///Once promoted, indices follow the iteration order of the hash table, so this is linear in `index`.
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        match self.is_promoted() {
+            //SAFETY: upheld by caller
+            false => unsafe { self.linear.get_index_unchecked_mut(index) },
+            //SAFETY: upheld by caller
+            true => unsafe { self.hashed.iter_mut().nth(index).unwrap_unchecked() },
+        }
+    }
+}
+
+impl<K: Eq, V, const THRESHOLD: usize, S: Default> Default for AdaptiveMap<K, V, THRESHOLD, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher + Default> TryFromIterator<(K, V)>
+    for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::default();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone, const THRESHOLD: usize, S: BuildHasher + Clone> TryClone
+    for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        let mut hashed = HashMap::with_hasher(self.hashed.hasher().clone());
+        hashed.try_reserve(self.hashed.len())?;
+        //no insertion can allocate, as room was reserved for every pair.
+        hashed.extend(self.hashed.iter().map(|(k, v)| (k.clone(), v.clone())));
+
+        Ok(Self {
+            linear: self.linear.try_clone()?,
+            hashed,
+            promoted: self.promoted,
+        })
+    }
+}
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher> IntoIterator
+    for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    type Item = (K, V);
+
+    type IntoIter =
+        std::iter::Chain<std::vec::IntoIter<(K, V)>, std::collections::hash_map::IntoIter<K, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        //at most one of the two is non-empty.
+        self.linear.into_iter().chain(self.hashed)
+    }
+}
+
+crate::std_traits::map_traits!([K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher] AdaptiveMap<K, V, THRESHOLD, S>);
+
+impl<K: Eq + Hash, V, const THRESHOLD: usize, S: BuildHasher, Q: ?Sized + Hash + Eq>
+    std::ops::Index<&Q> for AdaptiveMap<K, V, THRESHOLD, S>
+where
+    K: Borrow<Q>,
+{
+    type Output = V;
+
+    ///Returns a reference to the value corresponding to `key`, hashing the key once promoted like `get`.
+    ///
+    ///# Panics
+    ///Panics if `key` is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in map")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<
+        K: Eq + Hash + serde::Serialize,
+        V: PartialEq + serde::Serialize,
+        const THRESHOLD: usize,
+        S: BuildHasher,
+    > serde::Serialize for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    fn serialize<S2>(&self, serializer: S2) -> Result<S2::Ok, S2::Error>
+    where
+        S2: serde::Serializer,
+    {
+        crate::serde::serialize_fallible_map(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<
+        'de,
+        K: Eq + Hash + serde::Deserialize<'de>,
+        V: serde::Deserialize<'de>,
+        const THRESHOLD: usize,
+        S: BuildHasher + Default,
+    > serde::Deserialize<'de> for AdaptiveMap<K, V, THRESHOLD, S>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
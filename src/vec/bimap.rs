@@ -0,0 +1,115 @@
+use std::{
+    collections::TryReserveError,
+    hash::{Hash, Hasher},
+};
+
+use crate::{bimap::BiMapIndexMut, FallibleLinearBiMap, TryClone, TryExtendError, TryFromIterator};
+
+///A bidirectional map backed by a Vector, which can be searched by either value of its pairs.
+///Useful for small lookup tables which are read in both directions, like enum to name tables.
+///
+///Like the other maps in this crate, two bimaps are equal if they hold the same pairs in any order.
+#[derive(Debug)]
+pub struct BiVecMap<L: Eq, R: Eq> {
+    vector: Vec<(L, R)>,
+}
+
+impl<L: Eq, R: Eq> BiVecMap<L, R> {
+    ///Creates a new, empty BiVecMap.
+    ///Calls Vec::new() internally.
+    pub const fn new() -> Self {
+        Self { vector: Vec::new() }
+    }
+
+    ///Creates a new, empty BiVecMap with capacity set to the provide value.
+    ///Calls Vec::with_capacity() internally.
+    pub fn with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Vec::try_with_capacity(capacity).map(|vector| Self { vector })
+    }
+}
+
+impl<L: Eq, R: Eq> FallibleLinearBiMap<L, R> for BiVecMap<L, R> {
+    type Backing = Vec<(L, R)>;
+    type InsertionError = TryReserveError;
+
+    fn into_inner(self) -> Self::Backing {
+        self.vector
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (L, R)>
+    where
+        L: 'a,
+        R: 'a,
+    {
+        self.vector.iter()
+    }
+
+    fn len(&self) -> usize {
+        self.vector.len()
+    }
+}
+
+impl<L: Eq, R: Eq> BiMapIndexMut<L, R> for BiVecMap<L, R> {
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> &mut (L, R) {
+        //SAFETY: upheld by caller
+        unsafe { self.vector.get_unchecked_mut(index) }
+    }
+
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (L, R) {
+        //in bounds as upheld by the caller, so this cannot panic.
+        self.vector.remove(index)
+    }
+
+    fn push_unchecked(
+        &mut self,
+        left: L,
+        right: R,
+    ) -> Result<(), <Self as FallibleLinearBiMap<L, R>>::InsertionError> {
+        if self.vector.capacity() <= self.vector.len() {
+            self.vector.try_reserve(1)?;
+        }
+        self.vector.push((left, right));
+        Ok(())
+    }
+}
+
+impl<L: Eq, R: Eq> Default for BiVecMap<L, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L: Eq, R: Eq> TryFromIterator<(L, R)> for BiVecMap<L, R> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (L, R)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<L: Eq + Clone, R: Eq + Clone> TryClone for BiVecMap<L, R> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.vector.try_clone().map(|vector| Self { vector })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.vector.try_clone_from(&source.vector)
+    }
+}
+
+impl<L: Eq, R: Eq, B: FallibleLinearBiMap<L, R> + ?Sized> PartialEq<B> for BiVecMap<L, R> {
+    fn eq(&self, other: &B) -> bool {
+        //as left values are unique, finding every pair by its left value means both maps hold the same pairs.
+        self.len() == other.len() && self.iter().all(|(l, r)| other.get_by_left(l) == Some(r))
+    }
+}
+
+impl<L: Eq, R: Eq> Eq for BiVecMap<L, R> {}
+
+impl<L: Eq + Hash, R: Eq + Hash> Hash for BiVecMap<L, R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        crate::std_traits::hash_unordered(self.iter(), state)
+    }
+}
@@ -0,0 +1,386 @@
+use std::{
+    borrow::Borrow,
+    collections::TryReserveError,
+    hash::{BuildHasher, Hash, RandomState},
+};
+
+use crate::{
+    entry::{Entry, OccupiedEntry, VacantEntry},
+    policy::Reorder,
+    search::KeySearch,
+    soa::ExtractPairs,
+    FallibleLinearMap, LinearMapValuesMut, LinearMapView, MapExtract, MapIndex, MapIndexMut,
+    MapIterMut, PositionalLinearMap, TryClone, TryExtendError, TryFromIterator,
+};
+
+///A map backed by a Vector which stores a 16 bit fingerprint of each key's hash alongside it.
+///Useful for keys whose equality is expensive to check, like long strings: lookups compare the fingerprints first,
+///several at a time, so a full key comparison is only made about once per lookup.
+///
+///Only the inherent lookups, like `get` and `remove`, and `insert` and `entry` use the fingerprints, as the other
+///methods of the map traits can't require the borrowed form of the key to be `Hash`. Those fall back to a plain scan.
+pub struct FingerprintVecMap<K: Eq, V, S = RandomState> {
+    ///Fingerprints of the keys, in the same order as `pairs`.
+    fingerprints: Vec<u16>,
+    pairs: Vec<(K, V)>,
+    hasher: S,
+}
+
+impl<K: Eq, V> FingerprintVecMap<K, V, RandomState> {
+    ///Creates a new, empty FingerprintVecMap.
+    ///Calls Vec::new() internally.
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    ///Creates a new, empty FingerprintVecMap with capacity set to the provide value.
+    ///Calls Vec::with_capacity() internally.
+    pub fn with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K: Eq, V, S> FingerprintVecMap<K, V, S> {
+    ///Creates a new, empty FingerprintVecMap which fingerprints keys with `hasher`.
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            fingerprints: Vec::new(),
+            pairs: Vec::new(),
+            hasher,
+        }
+    }
+
+    ///Creates a new, empty FingerprintVecMap with capacity set to the provide value, which fingerprints keys with `hasher`.
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Result<Self, TryReserveError> {
+        Ok(Self {
+            fingerprints: Vec::try_with_capacity(capacity)?,
+            pairs: Vec::try_with_capacity(capacity)?,
+            hasher,
+        })
+    }
+
+    ///Returns a reference to the map's `BuildHasher`.
+    pub fn hasher(&self) -> &S {
+        &self.hasher
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> FingerprintVecMap<K, V, S> {
+    fn fingerprint<Q: ?Sized + Hash>(&self, key: &Q) -> u16 {
+        //the high bits are the best mixed by most hashers.
+        (self.hasher.hash_one(key) >> 48) as u16
+    }
+
+    ///Returns the index of the pair whose key is equal to `key`, only comparing the keys whose fingerprints match.
+    fn find<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        let fingerprint = self.fingerprint(key);
+        let mut start = 0;
+
+        while let Some(offset) = u16::position_in_keys(&self.fingerprints[start..], &fingerprint) {
+            let index = start + offset;
+
+            //SAFETY: there is a pair for every fingerprint.
+            match unsafe { self.pairs.get_unchecked(index) }.0.borrow() == key {
+                true => return Some(index),
+                false => start = index + 1,
+            }
+        }
+
+        None
+    }
+
+    ///Gets a reference to the value associated with the key. Will return None if that key is not in the map.
+    ///The key may be any borrowed form of the map's key type, but `Hash` and `Eq` on the borrowed form *must* match those for the key type.
+    pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.find(key)?;
+        //SAFETY: `find` only returns indices of pairs within the map.
+        Some(&unsafe { self.pairs.get_unchecked(index) }.1)
+    }
+
+    ///Gets a mutable reference to the value associated with the key. Will return None if that key is not in the map.
+    ///The key may be any borrowed form of the map's key type, but `Hash` and `Eq` on the borrowed form *must* match those for the key type.
+    pub fn get_mut<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.find(key)?;
+        //SAFETY: `find` only returns indices of pairs within the map.
+        Some(&mut unsafe { self.pairs.get_unchecked_mut(index) }.1)
+    }
+
+    ///Returns true if this map contains the given key. False otherwise.
+    ///The key may be any borrowed form of the map's key type, but `Hash` and `Eq` on the borrowed form *must* match those for the key type.
+    pub fn contains_key<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).is_some()
+    }
+
+    ///Tries to remove the entry associated with the given key, returning None if it is not found.
+    ///The key may be any borrowed form of the map's key type, but `Hash` and `Eq` on the borrowed form *must* match those for the key type.
+    pub fn remove_entry<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.find(key)?;
+        //SAFETY: `find` only returns indices of pairs within the map.
+        Some(unsafe { self.remove_index_unchecked(index) })
+    }
+
+    ///Tries to remove the value associated with the given key, returning None if it is not found.
+    ///The key may be any borrowed form of the map's key type, but `Hash` and `Eq` on the borrowed form *must* match those for the key type.
+    pub fn remove<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> FallibleLinearMap<K, V> for FingerprintVecMap<K, V, S> {
+    type Backing = Vec<(K, V)>;
+    type InsertionError = TryReserveError;
+
+    fn into_inner(self) -> Self::Backing {
+        self.pairs
+    }
+
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.index_of(key)?;
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        Some(unsafe { self.remove_index_unchecked(index) })
+    }
+
+    ///Inserts the provided value into the map. If the provided key is
+    ///found it will update the value. and return the old value. If not, this will allocate for a new key value pair.
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError> {
+        match self.find(&key) {
+            //SAFETY: `find` only returns indices of pairs within the map.
+            Some(index) => Ok(Some(std::mem::replace(
+                &mut unsafe { self.pairs.get_unchecked_mut(index) }.1,
+                value,
+            ))),
+            None => self.push_unchecked(key, value).map(|_| None),
+        }
+    }
+
+    fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V, Self>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        match self.find(&key) {
+            //SAFETY: `find` only returns indices of pairs within the map.
+            Some(index) => Entry::Occupied(unsafe { OccupiedEntry::new(self, index) }),
+            None => Entry::Vacant(VacantEntry::new(self, key)),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> LinearMapView<K, V> for FingerprintVecMap<K, V, S> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.pairs.iter().map(|(k, v)| (k, v))
+    }
+
+    fn len(&self) -> usize {
+        self.pairs.len()
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> LinearMapValuesMut<K, V> for FingerprintVecMap<K, V, S> {}
+
+impl<K: Eq + Hash, V, S: BuildHasher> MapIterMut<K, V> for FingerprintVecMap<K, V, S> {
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.pairs.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> MapExtract<K, V> for FingerprintVecMap<K, V, S> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        let pred = move |_: &u16, (k, v): &mut (K, V)| pred(k, v);
+        //SAFETY: there is a pair for every fingerprint.
+        unsafe { ExtractPairs::new(&mut self.fingerprints, &mut self.pairs, pred) }
+            .map(|(_, pair)| pair)
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> MapIndexMut<K, V> for FingerprintVecMap<K, V, S> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //in bounds as upheld by the caller, so this cannot panic.
+        self.fingerprints.remove(index);
+        self.pairs.remove(index)
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        let fingerprint = self.fingerprint(&key);
+
+        //reserve room in both columns before pushing to either, so that a failure leaves the map untouched.
+        self.reserve(1)?;
+        self.fingerprints.push(fingerprint);
+        self.pairs.push((key, value));
+        Ok(self.pairs.len() - 1)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.fingerprints.try_reserve(additional)?;
+        self.pairs.try_reserve(additional)
+    }
+
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.reserve(additional)
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> MapIndex<K, V> for FingerprintVecMap<K, V, S> {
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.pairs.get_unchecked(index) };
+        (k, v)
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.pairs.get_unchecked_mut(index) };
+        (k, v)
+    }
+}
+
+impl<K: Eq, V, S> Reorder for FingerprintVecMap<K, V, S> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        //SAFETY: upheld by caller
+        unsafe {
+            Reorder::swap_unchecked(&mut self.fingerprints, a, b);
+            Reorder::swap_unchecked(&mut self.pairs, a, b);
+        }
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //SAFETY: upheld by caller
+        unsafe {
+            self.fingerprints.move_to_front_unchecked(index);
+            self.pairs.move_to_front_unchecked(index);
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> PositionalLinearMap<K, V> for FingerprintVecMap<K, V, S> {}
+
+impl<K: Eq, V, S: Default> Default for FingerprintVecMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> TryFromIterator<(K, V)>
+    for FingerprintVecMap<K, V, S>
+{
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::default();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+//`try_clone_from` is left to its default, so that a panicking `clone` can never leave the columns at different lengths.
+impl<K: Eq + Clone, V: Clone, S: Clone> TryClone for FingerprintVecMap<K, V, S> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(Self {
+            fingerprints: self.fingerprints.try_clone()?,
+            pairs: self.pairs.try_clone()?,
+            hasher: self.hasher.clone(),
+        })
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> IntoIterator for FingerprintVecMap<K, V, S> {
+    type Item = (K, V);
+
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.pairs.into_iter()
+    }
+}
+
+crate::std_traits::map_traits!([K: Eq + Hash, V, S: BuildHasher] FingerprintVecMap<K, V, S>);
+
+impl<K: Eq + Hash, V, S: BuildHasher, Q: ?Sized + Hash + Eq> std::ops::Index<&Q>
+    for FingerprintVecMap<K, V, S>
+where
+    K: Borrow<Q>,
+{
+    type Output = V;
+
+    ///Returns a reference to the value corresponding to `key`, comparing fingerprints before keys like `get`.
+    ///
+    ///# Panics
+    ///Panics if `key` is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in map")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K: Eq + Hash + serde::Serialize, V: PartialEq + serde::Serialize, S: BuildHasher>
+    serde::Serialize for FingerprintVecMap<K, V, S>
+{
+    fn serialize<S2>(&self, serializer: S2) -> Result<S2::Ok, S2::Error>
+    where
+        S2: serde::Serializer,
+    {
+        crate::serde::serialize_fallible_map(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<
+        'de,
+        K: Eq + Hash + serde::Deserialize<'de>,
+        V: serde::Deserialize<'de>,
+        S: BuildHasher + Default,
+    > serde::Deserialize<'de> for FingerprintVecMap<K, V, S>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
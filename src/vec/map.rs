@@ -1,9 +1,12 @@
-use std::collections::TryReserveError;
+use std::{borrow::Borrow, collections::TryReserveError};
 
-use crate::{FallibleLinearMap, MapIterMut};
+use crate::{
+    policy::Reorder, search::KeySearch, FallibleLinearMap, LinearMapValuesMut, LinearMapView,
+    MapExtract, MapIndex, MapIndexMut, MapIterMut, PositionalLinearMap, TryClone, TryExtendError,
+    TryFromIterator,
+};
 
 ///A map type backed by a Vector. Useful for small collections whose size can change.
-#[derive(Debug, PartialEq, Eq, Hash, Default)]
 pub struct VecMap<K: Eq, V> {
     vector: Vec<(K, V)>,
 }
@@ -41,21 +44,16 @@ impl<K: Eq, V> FallibleLinearMap<K, V> for VecMap<K, V> {
         self.vector
     }
 
-    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (K, V)>
+    ///Tries to remove the entry associated with the given key, returning None if it is not found.
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
     where
-        K: 'a,
-        V: 'a,
+        K: Borrow<Q>,
     {
-        self.vector.iter()
-    }
-
-    ///Tries to remove the entry associated with the given key, returning None if it is not found.
-    fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
         let idx = self
             .vector
             .iter()
             .enumerate()
-            .find(|(_, (k, _))| k == key)
+            .find(|(_, (k, _))| k.borrow() == key)
             .map(|(i, _)| i)?;
 
         Some(self.vector.remove(idx))
@@ -75,22 +73,154 @@ impl<K: Eq, V> FallibleLinearMap<K, V> for VecMap<K, V> {
             }
         }
     }
+}
+
+impl<K: Eq, V> LinearMapView<K, V> for VecMap<K, V> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.vector.iter().map(|(k, v)| (k, v))
+    }
 
     fn len(&self) -> usize {
         self.vector.len()
     }
 }
 
+impl<K: Eq, V> LinearMapValuesMut<K, V> for VecMap<K, V> {}
+
 impl<K: Eq, V> MapIterMut<K, V> for VecMap<K, V> {
-    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut (K, V)>
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.vector.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Eq, V> MapExtract<K, V> for VecMap<K, V> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
     where
         K: 'a,
         V: 'a,
     {
-        self.vector.iter_mut()
+        self.vector.extract_if(.., move |(k, v)| pred(k, v))
+    }
+}
+
+impl<K: Eq, V> MapIndexMut<K, V> for VecMap<K, V> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //in bounds as upheld by the caller, so this cannot panic.
+        self.vector.remove(index)
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        if self.vector.capacity() <= self.vector.len() {
+            self.vector.try_reserve(1)?;
+        }
+        self.vector.push((key, value));
+        Ok(self.vector.len() - 1)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.vector.try_reserve(additional)
+    }
+
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.vector.try_reserve(additional)
+    }
+}
+
+impl<K: Eq, V> MapIndex<K, V> for VecMap<K, V> {
+    fn index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        K::position(&self.vector, key)
+    }
+
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.vector.get_unchecked(index) };
+        (k, v)
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.vector.get_unchecked_mut(index) };
+        (k, v)
+    }
+}
+
+impl<K: Eq, V> Reorder for VecMap<K, V> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        //SAFETY: upheld by caller
+        unsafe { Reorder::swap_unchecked(&mut self.vector, a, b) }
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //SAFETY: upheld by caller
+        unsafe { self.vector.move_to_front_unchecked(index) }
+    }
+}
+
+impl<K: Eq, V> PositionalLinearMap<K, V> for VecMap<K, V> {}
+
+impl<K: Eq, V> Default for VecMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V> TryFromIterator<(K, V)> for VecMap<K, V> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Eq + Clone, V: Clone> TryClone for VecMap<K, V> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.vector.try_clone().map(|vector| VecMap { vector })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.vector.try_clone_from(&source.vector)
+    }
+}
+
+impl<K: Eq, V> IntoIterator for VecMap<K, V> {
+    type Item = (K, V);
+
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vector.into_iter()
     }
 }
 
+crate::std_traits::map_traits!([K: Eq, V] VecMap<K, V>);
+crate::std_traits::map_index!([K: Eq, V] VecMap<K, V>);
+
 #[cfg(feature = "serde")]
 impl<'a, K: Eq + serde::Serialize, V: PartialEq + serde::Serialize> serde::Serialize
     for VecMap<K, V>
@@ -102,3 +232,15 @@ impl<'a, K: Eq + serde::Serialize, V: PartialEq + serde::Serialize> serde::Seria
         crate::serde::serialize_fallible_map(self, serializer)
     }
 }
+
+#[cfg(feature = "serde")]
+impl<'de, K: Eq + serde::Deserialize<'de>, V: serde::Deserialize<'de>> serde::Deserialize<'de>
+    for VecMap<K, V>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
@@ -1,7 +1,16 @@
 use std::collections::TryReserveError;
 
+use crate::TryClone;
+
+pub mod adaptive_map;
+pub mod bimap;
+pub mod fingerprint_map;
 pub mod map;
+pub mod multimap;
+pub mod self_organizing_map;
 pub mod set;
+pub mod soa_map;
+pub mod sorted_map;
 
 #[derive(Debug, Eq, PartialEq, Hash)]
 ///TODO: replace this with a rawvec type. RawVec panic on out_of_capacity as well.
@@ -41,3 +50,13 @@ impl<T> self::Vec<T> {
         self.inner.into_iter()
     }
 }
+
+impl<T: Clone> TryClone for self::Vec<T> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.inner.try_clone().map(|inner| Vec { inner })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.inner.try_clone_from(&source.inner)
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::TryReserveError;
+
+use crate::{
+    FallibleLinearMultiMap, MapExtract, MapIterMut, TryClone, TryExtendError, TryFromIterator,
+};
+
+///A multimap backed by a Vector: a map which may hold any number of values for the same key, in insertion order.
+///Useful for small collections with repeated keys, like the headers of an http request.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct VecMultiMap<K: Eq, V> {
+    vector: Vec<(K, V)>,
+}
+
+impl<K: Eq, V> VecMultiMap<K, V> {
+    ///Creates a new, empty VecMultiMap.
+    ///Calls Vec::new() internally.
+    pub const fn new() -> Self {
+        Self { vector: Vec::new() }
+    }
+
+    ///Creates a new, empty VecMultiMap with capacity set to the provide value.
+    ///Calls Vec::with_capacity() internally.
+    pub fn with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Vec::try_with_capacity(capacity).map(|vector| Self { vector })
+    }
+
+    ///Creates a new VecMultiMap from the supplied vector.
+    ///Unlike the other maps in this crate, repeated keys are permitted so this cannot violate any invariant.
+    pub const fn from_vec(vector: Vec<(K, V)>) -> Self {
+        Self { vector }
+    }
+
+    ///Returns the pairs of this map as a slice, in insertion order.
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.vector
+    }
+}
+
+impl<K: Eq, V> FallibleLinearMultiMap<K, V> for VecMultiMap<K, V> {
+    type Backing = Vec<(K, V)>;
+    type InsertionError = TryReserveError;
+
+    fn insert(&mut self, key: K, value: V) -> Result<(), Self::InsertionError> {
+        if self.vector.capacity() <= self.vector.len() {
+            self.vector.try_reserve(1)?;
+        }
+        self.vector.push((key, value));
+        Ok(())
+    }
+
+    fn into_inner(self) -> Self::Backing {
+        self.vector
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (K, V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.vector.iter()
+    }
+
+    fn len(&self) -> usize {
+        self.vector.len()
+    }
+
+    fn try_extend<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryExtendError<Self::InsertionError>> {
+        let iter = iter.into_iter();
+        self.vector
+            .try_reserve(iter.size_hint().0)
+            .map_err(|e| TryExtendError::new(e, 0))?;
+        crate::extend::try_extend_with(iter, |(k, v)| self.insert(k, v))
+    }
+}
+
+impl<K: Eq, V> MapIterMut<K, V> for VecMultiMap<K, V> {
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.vector.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Eq, V> MapExtract<K, V> for VecMultiMap<K, V> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.vector.extract_if(.., move |(k, v)| pred(k, v))
+    }
+}
+
+impl<K: Eq, V> Default for VecMultiMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V> TryFromIterator<(K, V)> for VecMultiMap<K, V> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Eq + Clone, V: Clone> TryClone for VecMultiMap<K, V> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.vector.try_clone().map(|vector| Self { vector })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.vector.try_clone_from(&source.vector)
+    }
+}
@@ -0,0 +1,241 @@
+use std::{borrow::Borrow, collections::TryReserveError, marker::PhantomData};
+
+use crate::{
+    AccessPolicy, FallibleLinearMap, LinearMapValuesMut, LinearMapView, MapExtract, MapIndex,
+    MapIndexMut, MapIterMut, MoveToFront, TryClone, TryExtendError, TryFromIterator, VecMap,
+};
+
+///A map backed by a `VecMap` which moves pairs towards the front as they are accessed, according to its `AccessPolicy`.
+///Useful when lookups are heavily skewed towards a few keys, as those keys end up at the front of every scan.
+///
+///Only `get_and_promote` and `get_mut_and_promote` reorder the map: every other lookup, including `get_mut` and those
+///made through `LinearMapView`, leaves it untouched. Reordering a Vector shifts every pair in front of the accessed one,
+///see `SelfOrganizingDequeMap` for a map which only shifts the shorter side.
+pub struct SelfOrganizingVecMap<K: Eq, V, P: AccessPolicy = MoveToFront> {
+    map: VecMap<K, V>,
+    policy: PhantomData<P>,
+}
+
+impl<K: Eq, V, P: AccessPolicy> SelfOrganizingVecMap<K, V, P> {
+    ///Creates a new, empty SelfOrganizingVecMap.
+    ///Calls Vec::new() internally.
+    pub fn new() -> Self {
+        Self::from_map(VecMap::new())
+    }
+
+    ///Creates a new, empty SelfOrganizingVecMap with capacity set to the provide value.
+    ///Calls Vec::with_capacity() internally.
+    pub fn with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        VecMap::with_capacity(capacity).map(Self::from_map)
+    }
+
+    fn from_map(map: VecMap<K, V>) -> Self {
+        Self {
+            map,
+            policy: PhantomData,
+        }
+    }
+
+    ///Finds the key and promotes it according to the access policy, returning its new index.
+    fn find_and_promote<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.map.index_of(key)?;
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        Some(unsafe { P::promote(&mut self.map, index) })
+    }
+
+    ///Gets a reference to the value associated with the key, promoting it towards the front of the map.
+    ///Will return None if that key is not in the map.
+    pub fn get_and_promote<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.find_and_promote(key)?;
+        //SAFETY: `find_and_promote` only returns indices of pairs within the map.
+        Some(unsafe { self.map.get_index_unchecked(index) }.1)
+    }
+
+    ///Gets a mutable reference to the value associated with the key, promoting it towards the front of the map.
+    ///Will return None if that key is not in the map.
+    pub fn get_mut_and_promote<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.find_and_promote(key)?;
+        //SAFETY: `find_and_promote` only returns indices of pairs within the map.
+        Some(unsafe { self.map.get_index_unchecked_mut(index) }.1)
+    }
+}
+
+impl<K: Eq, V, P: AccessPolicy> FallibleLinearMap<K, V> for SelfOrganizingVecMap<K, V, P> {
+    type Backing = Vec<(K, V)>;
+    type InsertionError = TryReserveError;
+
+    fn into_inner(self) -> Self::Backing {
+        self.map.into_inner()
+    }
+
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        self.map.remove_entry(key)
+    }
+
+    ///Inserts the provided value into the map. If the provided key is
+    ///found it will update the value in place and return the old value. If not, this may allocate for a new key value pair
+    ///which is placed at the back of the map.
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError> {
+        self.map.insert(key, value)
+    }
+}
+
+impl<K: Eq, V, P: AccessPolicy> LinearMapView<K, V> for SelfOrganizingVecMap<K, V, P> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.map.iter()
+    }
+
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+}
+
+impl<K: Eq, V, P: AccessPolicy> LinearMapValuesMut<K, V> for SelfOrganizingVecMap<K, V, P> {}
+
+impl<K: Eq, V, P: AccessPolicy> MapIterMut<K, V> for SelfOrganizingVecMap<K, V, P> {
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.map.iter_mut()
+    }
+}
+
+impl<K: Eq, V, P: AccessPolicy> MapExtract<K, V> for SelfOrganizingVecMap<K, V, P> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.map.extract_pairs_if(pred)
+    }
+}
+
+impl<K: Eq, V, P: AccessPolicy> MapIndexMut<K, V> for SelfOrganizingVecMap<K, V, P> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //SAFETY: upheld by caller
+        unsafe { self.map.remove_index_unchecked(index) }
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.map.push_unchecked(key, value)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.map.reserve(additional)
+    }
+
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.map.reserve_hint(additional)
+    }
+}
+
+impl<K: Eq, V, P: AccessPolicy> MapIndex<K, V> for SelfOrganizingVecMap<K, V, P> {
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        unsafe { self.map.get_index_unchecked(index) }
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        unsafe { self.map.get_index_unchecked_mut(index) }
+    }
+
+    fn index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        self.map.index_of(key)
+    }
+}
+
+impl<K: Eq, V, P: AccessPolicy> Default for SelfOrganizingVecMap<K, V, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V, P: AccessPolicy> TryFromIterator<(K, V)> for SelfOrganizingVecMap<K, V, P> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        VecMap::try_from_iter(iter).map(Self::from_map)
+    }
+}
+
+impl<K: Eq + Clone, V: Clone, P: AccessPolicy> TryClone for SelfOrganizingVecMap<K, V, P> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.map.try_clone().map(Self::from_map)
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.map.try_clone_from(&source.map)
+    }
+}
+
+impl<K: Eq, V, P: AccessPolicy> IntoIterator for SelfOrganizingVecMap<K, V, P> {
+    type Item = (K, V);
+
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+crate::std_traits::map_traits!([K: Eq, V, P: AccessPolicy] SelfOrganizingVecMap<K, V, P>);
+crate::std_traits::map_index!([K: Eq, V, P: AccessPolicy] SelfOrganizingVecMap<K, V, P>);
+
+#[cfg(feature = "serde")]
+impl<K: Eq + serde::Serialize, V: PartialEq + serde::Serialize, P: AccessPolicy> serde::Serialize
+    for SelfOrganizingVecMap<K, V, P>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.map.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K: Eq + serde::Deserialize<'de>, V: serde::Deserialize<'de>, P: AccessPolicy>
+    serde::Deserialize<'de> for SelfOrganizingVecMap<K, V, P>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        VecMap::deserialize(deserializer).map(Self::from_map)
+    }
+}
@@ -1,11 +1,10 @@
 use std::collections::TryReserveError;
 
-use crate::{FallibleLinearMap, FallibleLinearSet};
+use crate::{FallibleLinearMap, FallibleLinearSet, TryClone, TryExtendError, TryFromIterator};
 
 use super::map::VecMap;
 
 ///A set backed by a VecMap where the value for each key is ().
-#[derive(Debug, PartialEq, Eq, Hash, Default)]
 pub struct VecSet<T: Eq> {
     map: VecMap<T, ()>,
 }
@@ -52,6 +51,34 @@ impl<T: Eq> VecSet<T> {
     }
 }
 
+impl<T: Eq> Default for VecSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq> TryFromIterator<T> for VecSet<T> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        let mut set = Self::new();
+        set.try_extend(iter)?;
+        Ok(set)
+    }
+}
+
+impl<T: Eq + Clone> TryClone for VecSet<T> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.map.try_clone().map(|map| VecSet { map })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.map.try_clone_from(&source.map)
+    }
+}
+
+crate::std_traits::set_traits!([T: Eq] VecSet<T>);
+
 #[cfg(feature = "serde")]
 impl<'a, T: Eq + serde::Serialize> serde::Serialize for VecSet<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -61,3 +88,13 @@ impl<'a, T: Eq + serde::Serialize> serde::Serialize for VecSet<T> {
         crate::serde::serialize_fallible_set(self, serializer)
     }
 }
+
+#[cfg(feature = "serde")]
+impl<'de, T: Eq + serde::Deserialize<'de>> serde::Deserialize<'de> for VecSet<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_set(deserializer)
+    }
+}
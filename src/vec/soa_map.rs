@@ -0,0 +1,261 @@
+use std::{borrow::Borrow, collections::TryReserveError};
+
+use crate::{
+    policy::Reorder, search::KeySearch, soa::ExtractPairs, FallibleLinearMap, LinearMapValuesMut,
+    LinearMapView, MapExtract, MapIndex, MapIndexMut, MapIterMut, PositionalLinearMap, TryClone,
+    TryExtendError, TryFromIterator,
+};
+
+///A map backed by two Vectors: one holding the keys and the other their values, in the same order.
+///Searching the map only touches the keys, so lookups stay cache friendly when the values are large.
+pub struct SoaVecMap<K: Eq, V> {
+    keys: Vec<K>,
+    values: Vec<V>,
+}
+
+impl<K: Eq, V> SoaVecMap<K, V> {
+    ///Creates a new, empty SoaVecMap.
+    ///Calls Vec::new() internally.
+    pub fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    ///Creates a new, empty SoaVecMap with room for `capacity` pairs.
+    ///Calls Vec::with_capacity() internally, once for the keys and once for the values.
+    pub fn with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Ok(Self {
+            keys: Vec::try_with_capacity(capacity)?,
+            values: Vec::try_with_capacity(capacity)?,
+        })
+    }
+
+    ///Returns the keys of this map, in the same order as its values.
+    pub fn keys_slice(&self) -> &[K] {
+        &self.keys
+    }
+
+    ///Returns the values of this map, in the same order as its keys.
+    pub fn values_slice(&self) -> &[V] {
+        &self.values
+    }
+}
+
+impl<K: Eq, V> FallibleLinearMap<K, V> for SoaVecMap<K, V> {
+    type Backing = (Vec<K>, Vec<V>);
+    type InsertionError = TryReserveError;
+
+    fn into_inner(self) -> Self::Backing {
+        (self.keys, self.values)
+    }
+
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.index_of(key)?;
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        Some(unsafe { self.remove_index_unchecked(index) })
+    }
+
+    ///Inserts the provided value into the SoaVecMap. If the provided key is
+    ///found it will update the value. and return the old value. If not, this will allocate for a new key value pair.
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError> {
+        match self.index_of(&key) {
+            //SAFETY: `index_of` only returns indices of pairs within the map.
+            Some(index) => Ok(Some(std::mem::replace(
+                unsafe { self.values.get_unchecked_mut(index) },
+                value,
+            ))),
+            None => self.push_unchecked(key, value).map(|_| None),
+        }
+    }
+}
+
+impl<K: Eq, V> LinearMapView<K, V> for SoaVecMap<K, V> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.keys.iter().zip(self.values.iter())
+    }
+
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+}
+
+impl<K: Eq, V> LinearMapValuesMut<K, V> for SoaVecMap<K, V> {}
+
+impl<K: Eq, V> MapIterMut<K, V> for SoaVecMap<K, V> {
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.keys.iter().zip(self.values.iter_mut())
+    }
+}
+
+impl<K: Eq, V> MapExtract<K, V> for SoaVecMap<K, V> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        //SAFETY: there is a value for every key.
+        unsafe { ExtractPairs::new(&mut self.keys, &mut self.values, pred) }
+    }
+}
+
+impl<K: Eq, V> MapIndexMut<K, V> for SoaVecMap<K, V> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //in bounds as upheld by the caller, so this cannot panic.
+        (self.keys.remove(index), self.values.remove(index))
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        //reserve room in both columns before pushing to either, so that a failure leaves the map untouched.
+        self.reserve(1)?;
+        self.keys.push(key);
+        self.values.push(value);
+        Ok(self.keys.len() - 1)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.keys.try_reserve(additional)?;
+        self.values.try_reserve(additional)
+    }
+
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.reserve(additional)
+    }
+}
+
+impl<K: Eq, V> MapIndex<K, V> for SoaVecMap<K, V> {
+    fn index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        K::position_in_keys(&self.keys, key)
+    }
+
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        unsafe {
+            (
+                self.keys.get_unchecked(index),
+                self.values.get_unchecked(index),
+            )
+        }
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        unsafe {
+            (
+                self.keys.get_unchecked(index),
+                self.values.get_unchecked_mut(index),
+            )
+        }
+    }
+}
+
+impl<K: Eq, V> Reorder for SoaVecMap<K, V> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        //SAFETY: upheld by caller
+        unsafe {
+            Reorder::swap_unchecked(&mut self.keys, a, b);
+            Reorder::swap_unchecked(&mut self.values, a, b);
+        }
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //SAFETY: upheld by caller
+        unsafe {
+            self.keys.move_to_front_unchecked(index);
+            self.values.move_to_front_unchecked(index);
+        }
+    }
+}
+
+impl<K: Eq, V> PositionalLinearMap<K, V> for SoaVecMap<K, V> {}
+
+impl<K: Eq, V> Default for SoaVecMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V> TryFromIterator<(K, V)> for SoaVecMap<K, V> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+//`try_clone_from` is left to its default, so that a panicking `clone` can never leave the columns at different lengths.
+impl<K: Eq + Clone, V: Clone> TryClone for SoaVecMap<K, V> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        Ok(Self {
+            keys: self.keys.try_clone()?,
+            values: self.values.try_clone()?,
+        })
+    }
+}
+
+impl<K: Eq, V> IntoIterator for SoaVecMap<K, V> {
+    type Item = (K, V);
+
+    type IntoIter = std::iter::Zip<std::vec::IntoIter<K>, std::vec::IntoIter<V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter().zip(self.values)
+    }
+}
+
+crate::std_traits::map_traits!([K: Eq, V] SoaVecMap<K, V>);
+crate::std_traits::map_index!([K: Eq, V] SoaVecMap<K, V>);
+
+#[cfg(feature = "serde")]
+impl<K: Eq + serde::Serialize, V: PartialEq + serde::Serialize> serde::Serialize
+    for SoaVecMap<K, V>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serde::serialize_fallible_map(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K: Eq + serde::Deserialize<'de>, V: serde::Deserialize<'de>> serde::Deserialize<'de>
+    for SoaVecMap<K, V>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
@@ -0,0 +1,306 @@
+use std::{borrow::Borrow, collections::TryReserveError};
+
+use crate::{
+    sorted::search, Entry, FallibleLinearMap, LinearMapValuesMut, LinearMapView, MapExtract,
+    MapIndex, MapIndexMut, MapIterMut, OccupiedEntry, SortedLinearMap, TryClone, TryExtendError,
+    TryFromIterator, VacantEntry,
+};
+
+///A map backed by a Vector which keeps its pairs sorted by key, so that lookups are O(log n) binary searches.
+///Useful for larger collections whose size can change, at the cost of shifting pairs on every insertion and removal.
+pub struct SortedVecMap<K: Ord, V> {
+    vector: Vec<(K, V)>,
+}
+
+impl<K: Ord, V> SortedVecMap<K, V> {
+    ///Creates a new, empty SortedVecMap.
+    ///Calls Vec::new() internally.
+    pub fn new() -> SortedVecMap<K, V> {
+        SortedVecMap { vector: Vec::new() }
+    }
+
+    ///Creates a new, empty SortedVecMap with capacity set to the provide value.
+    ///Calls Vec::with_capacity() internally.
+    pub fn with_capacity(capacity: usize) -> Result<SortedVecMap<K, V>, TryReserveError> {
+        Vec::try_with_capacity(capacity).map(|vector| SortedVecMap { vector })
+    }
+
+    ///Creates a new SortedVecMap from the supplied vector.
+    ///
+    ///SAFETY: improper use of this method - initializing with unsorted or duplicate keys - will NOT create memory unsafety,
+    ///but lookups will return incorrect results as they assume the keys are sorted and unique.
+    pub const unsafe fn from_vec_unchecked(vector: Vec<(K, V)>) -> SortedVecMap<K, V> {
+        SortedVecMap { vector }
+    }
+
+    ///Returns the pairs of this map as a slice, sorted by key.
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.vector
+    }
+
+    ///Gets a reference to the value associated with the key, found by binary search.
+    ///Will return None if that key is not in the map.
+    pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = search(self, key).ok()?;
+        //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+        Some(unsafe { self.get_index_unchecked(index) }.1)
+    }
+
+    ///Gets a mutable reference to the value associated with the key, found by binary search.
+    ///Will return None if that key is not in the map.
+    pub fn get_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = search(self, key).ok()?;
+        //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+        Some(unsafe { self.get_index_unchecked_mut(index) }.1)
+    }
+
+    ///Returns true if this map contains the given key, found by binary search. False otherwise.
+    pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        search(self, key).is_ok()
+    }
+
+    ///Tries to remove the entry associated with the given key, found by binary search, returning None if it is not found.
+    pub fn remove_entry<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let index = search(self, key).ok()?;
+        Some(self.vector.remove(index))
+    }
+
+    ///Tries to remove the value associated with the given key, found by binary search, returning None if it is not found.
+    pub fn remove<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    ///Gets the given key's corresponding entry in the map for in-place manipulation, found by binary search.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, Self> {
+        match search(self, &key) {
+            //SAFETY: `search` only returns `Ok` with indices of pairs within the map.
+            Ok(index) => Entry::Occupied(unsafe { OccupiedEntry::new(self, index) }),
+            Err(_) => Entry::Vacant(VacantEntry::new(self, key)),
+        }
+    }
+
+    ///Inserts the pair at `index`, which must be where `key` belongs in the sorted order.
+    fn insert_at(&mut self, index: usize, key: K, value: V) -> Result<(), TryReserveError> {
+        if self.vector.capacity() <= self.vector.len() {
+            self.vector.try_reserve(1)?;
+        }
+        self.vector.insert(index, (key, value));
+        Ok(())
+    }
+}
+
+impl<K: Ord, V> FallibleLinearMap<K, V> for SortedVecMap<K, V> {
+    type Backing = Vec<(K, V)>;
+    type InsertionError = TryReserveError;
+
+    fn into_inner(self) -> Self::Backing {
+        self.vector
+    }
+
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let idx = self.vector.iter().position(|(k, _)| k.borrow() == key)?;
+
+        Some(self.vector.remove(idx))
+    }
+
+    ///Gets the given key's corresponding entry in the map, found by binary search like the inherent `entry`.
+    fn entry<'a>(&'a mut self, key: K) -> Entry<'a, K, V, Self>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        SortedVecMap::entry(self, key)
+    }
+
+    ///Inserts the provided pair where its key belongs in the sorted order. If the provided key is
+    ///found it will update the value and return the old value. If not, this may allocate for a new key value pair.
+    fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Self::InsertionError> {
+        match search(self, &key) {
+            Ok(index) => Ok(Some(std::mem::replace(&mut self.vector[index].1, value))),
+            Err(index) => self.insert_at(index, key, value).map(|_| None),
+        }
+    }
+}
+
+impl<K: Ord, V> LinearMapView<K, V> for SortedVecMap<K, V> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.vector.iter().map(|(k, v)| (k, v))
+    }
+
+    fn len(&self) -> usize {
+        self.vector.len()
+    }
+}
+
+impl<K: Ord, V> LinearMapValuesMut<K, V> for SortedVecMap<K, V> {}
+
+impl<K: Ord, V> SortedLinearMap<K, V> for SortedVecMap<K, V> {}
+
+impl<K: Ord, V> MapIterMut<K, V> for SortedVecMap<K, V> {
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.vector.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Ord, V> MapExtract<K, V> for SortedVecMap<K, V> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.vector.extract_if(.., move |(k, v)| pred(k, v))
+    }
+}
+
+impl<K: Ord, V> MapIndexMut<K, V> for SortedVecMap<K, V> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //in bounds as upheld by the caller, so this cannot panic.
+        self.vector.remove(index)
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        let index = search(self, &key).unwrap_or_else(|index| index);
+        self.insert_at(index, key, value)?;
+        Ok(index)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.vector.try_reserve(additional)
+    }
+
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.vector.try_reserve(additional)
+    }
+}
+
+impl<K: Ord, V> MapIndex<K, V> for SortedVecMap<K, V> {
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.vector.get_unchecked(index) };
+        (k, v)
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.vector.get_unchecked_mut(index) };
+        (k, v)
+    }
+}
+
+impl<K: Ord, V> Default for SortedVecMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> TryFromIterator<(K, V)> for SortedVecMap<K, V> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> TryClone for SortedVecMap<K, V> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.vector
+            .try_clone()
+            .map(|vector| SortedVecMap { vector })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.vector.try_clone_from(&source.vector)
+    }
+}
+
+impl<K: Ord, V> IntoIterator for SortedVecMap<K, V> {
+    type Item = (K, V);
+
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vector.into_iter()
+    }
+}
+
+crate::std_traits::map_traits!([K: Ord, V] SortedVecMap<K, V>);
+
+impl<K: Ord, V, Q: ?Sized + Ord> std::ops::Index<&Q> for SortedVecMap<K, V>
+where
+    K: Borrow<Q>,
+{
+    type Output = V;
+
+    ///Returns a reference to the value corresponding to `key`, found by binary search like `get`.
+    ///
+    ///# Panics
+    ///Panics if `key` is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in map")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K: Ord + serde::Serialize, V: PartialEq + serde::Serialize> serde::Serialize
+    for SortedVecMap<K, V>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serde::serialize_fallible_map(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K: Ord + serde::Deserialize<'de>, V: serde::Deserialize<'de>> serde::Deserialize<'de>
+    for SortedVecMap<K, V>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
@@ -0,0 +1,154 @@
+use std::{borrow::Borrow, collections::TryReserveError};
+
+use crate::{DequeMap, FallibleLinearMap, LinearMapView, MapIndex, MapIndexMut, TryClone};
+
+///A least recently used cache backed by a `DequeMap`, holding at most a fixed number of pairs.
+///Useful for small caches, where scanning a few pairs beats hashing the key.
+///
+///All of the memory the cache will ever need is reserved when it is created, so no other method allocates.
+///Pairs are kept in order of use: the least recently used pair is at the front and is the first to be evicted.
+#[derive(Debug)]
+pub struct LinearLru<K: Eq, V> {
+    map: DequeMap<K, V>,
+    capacity: usize,
+}
+
+impl<K: Eq, V> LinearLru<K, V> {
+    ///Creates a new, empty LinearLru which holds at most `capacity` pairs.
+    ///Reserves room for every pair up front, returning an error if that allocation fails.
+    pub fn with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        let mut map = DequeMap::new();
+        map.reserve(capacity)?;
+        Ok(Self { map, capacity })
+    }
+
+    ///Returns the maximum number of pairs this cache can hold.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    ///Returns the number of pairs in this cache.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    ///Returns true if this cache holds no pairs.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    ///Appends the pair as the most recently used, returning its index.
+    ///SAFETY: UB if the cache is full.
+    unsafe fn push(&mut self, key: K, value: V) -> usize {
+        //room for `capacity` pairs was reserved on creation, so this never reallocates and cannot fail.
+        unsafe { self.map.push_unchecked(key, value).unwrap_unchecked() }
+    }
+
+    ///Marks the pair at `index` as the most recently used, returning its new index.
+    ///SAFETY: UB if `index` is >= the length of the cache.
+    unsafe fn promote(&mut self, index: usize) -> usize {
+        //SAFETY: upheld by caller, and the pair which was just removed leaves room to push it back.
+        unsafe {
+            let (key, value) = self.map.remove_index_unchecked(index);
+            self.push(key, value)
+        }
+    }
+
+    ///Gets a reference to the value associated with the key, marking it as the most recently used.
+    ///Will return None if that key is not in the cache.
+    pub fn get<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        let index = unsafe { self.promote(self.map.index_of(key)?) };
+        //SAFETY: `promote` returns the new index of the pair.
+        Some(unsafe { self.map.get_index_unchecked(index) }.1)
+    }
+
+    ///Gets a mutable reference to the value associated with the key, marking it as the most recently used.
+    ///Will return None if that key is not in the cache.
+    pub fn get_mut<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        //SAFETY: `index_of` only returns indices of pairs within the map.
+        let index = unsafe { self.promote(self.map.index_of(key)?) };
+        //SAFETY: `promote` returns the new index of the pair.
+        Some(unsafe { self.map.get_index_unchecked_mut(index) }.1)
+    }
+
+    ///Gets a reference to the value associated with the key without changing the order of use.
+    ///Will return None if that key is not in the cache.
+    pub fn peek<Q: ?Sized + Eq>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.map.get(key)
+    }
+
+    ///Returns true if this cache contains the given key, without changing the order of use. False otherwise.
+    pub fn contains_key<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.map.contains_key(key)
+    }
+
+    ///Inserts the pair into the cache as the most recently used.
+    ///Returns the pair it displaced: the previous pair with the same key if there was one, else the least recently used pair
+    ///if the cache was full. A cache with a capacity of zero hands the provided pair straight back.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        let displaced = match self.map.index_of(&key) {
+            //SAFETY: `index_of` only returns indices of pairs within the map.
+            Some(index) => Some(unsafe { self.map.remove_index_unchecked(index) }),
+            None => match self.len() < self.capacity {
+                true => None,
+                false => match self.pop_lru() {
+                    Some(evicted) => Some(evicted),
+                    None => return Some((key, value)),
+                },
+            },
+        };
+
+        //SAFETY: either the cache was not full, or a pair was just removed to make room.
+        unsafe { self.push(key, value) };
+        displaced
+    }
+
+    ///Tries to remove the value associated with the given key, returning None if it is not found.
+    pub fn remove<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.map.remove(key)
+    }
+
+    ///Removes and returns the least recently used pair, or None if the cache is empty.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        match self.is_empty() {
+            true => None,
+            //SAFETY: the cache is not empty.
+            false => Some(unsafe { self.map.remove_index_unchecked(0) }),
+        }
+    }
+
+    ///Removes every pair from the cache, keeping its reserved memory.
+    pub fn clear(&mut self) {
+        self.map.drain().for_each(drop)
+    }
+
+    ///Iterates over the pairs in this cache from the least to the most recently used.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.map.iter()
+    }
+}
+
+impl<K: Eq + Clone, V: Clone> TryClone for LinearLru<K, V> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        //the copy reserves room for `capacity` pairs too, so that it never allocates either.
+        let mut lru = Self::with_capacity(self.capacity)?;
+        lru.map.try_clone_from(&self.map)?;
+        Ok(lru)
+    }
+}
@@ -1,6 +1,13 @@
-use std::collections::{TryReserveError, VecDeque};
+use std::{
+    borrow::Borrow,
+    collections::{TryReserveError, VecDeque},
+};
 
-use crate::{FallibleLinearMap, MapIterMut};
+use crate::{
+    policy::Reorder, search::KeySearch, FallibleLinearMap, LinearMapValuesMut, LinearMapView,
+    MapExtract, MapIndex, MapIndexMut, MapIterMut, PositionalLinearMap, TryClone, TryExtendError,
+    TryFromIterator,
+};
 
 pub struct DequeMap<K: Eq, V> {
     deque: VecDeque<(K, V)>,
@@ -58,40 +65,170 @@ impl<K: Eq, V> FallibleLinearMap<K, V> for DequeMap<K, V> {
         self.deque
     }
 
-    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (K, V)>
+    fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
     where
-        K: 'a,
-        V: 'a,
+        K: Borrow<Q>,
     {
-        self.deque.iter()
-    }
-
-    fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
         let idx = self
             .deque
             .iter()
             .enumerate()
-            .find(|(_, (k, _))| k == key)
+            .find(|(_, (k, _))| k.borrow() == key)
             .map(|(i, _)| i)?;
 
         self.deque.remove(idx)
     }
+}
+
+impl<K: Eq, V> LinearMapView<K, V> for DequeMap<K, V> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.deque.iter().map(|(k, v)| (k, v))
+    }
 
     fn len(&self) -> usize {
         self.deque.len()
     }
 }
 
+impl<K: Eq, V> LinearMapValuesMut<K, V> for DequeMap<K, V> {}
+
 impl<K: Eq, V> MapIterMut<K, V> for DequeMap<K, V> {
-    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut (K, V)>
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.deque.iter_mut().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K: Eq, V> MapExtract<K, V> for DequeMap<K, V> {
+    fn extract_pairs_if<'a, F: FnMut(&K, &mut V) -> bool + 'a>(
+        &'a mut self,
+        mut pred: F,
+    ) -> impl Iterator<Item = (K, V)> + 'a
     where
         K: 'a,
         V: 'a,
     {
-        self.deque.iter_mut()
+        super::ExtractIf::new(&mut self.deque, move |(k, v)| pred(k, v))
+    }
+}
+
+impl<K: Eq, V> MapIndexMut<K, V> for DequeMap<K, V> {
+    unsafe fn remove_index_unchecked(&mut self, index: usize) -> (K, V) {
+        //SAFETY: upheld by caller
+        unsafe { self.deque.remove(index).unwrap_unchecked() }
+    }
+
+    fn push_unchecked(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<usize, <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        if self.deque.capacity() <= self.deque.len() {
+            self.deque.try_reserve(1)?;
+        }
+        self.deque.push_back((key, value));
+        Ok(self.deque.len() - 1)
+    }
+
+    fn reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.deque.try_reserve(additional)
+    }
+
+    fn reserve_hint(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), <Self as FallibleLinearMap<K, V>>::InsertionError> {
+        self.deque.try_reserve(additional)
     }
 }
 
+impl<K: Eq, V> MapIndex<K, V> for DequeMap<K, V> {
+    fn index_of<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        //the back of the ring buffer is only searched once every pair in front of it has been.
+        let (front, back) = self.deque.as_slices();
+
+        K::position(front, key).or_else(|| K::position(back, key).map(|i| front.len() + i))
+    }
+
+    unsafe fn get_index_unchecked(&self, index: usize) -> (&K, &V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.deque.get(index).unwrap_unchecked() };
+        (k, v)
+    }
+
+    unsafe fn get_index_unchecked_mut(&mut self, index: usize) -> (&K, &mut V) {
+        //SAFETY: upheld by caller
+        let (k, v) = unsafe { self.deque.get_mut(index).unwrap_unchecked() };
+        (k, v)
+    }
+}
+
+impl<K: Eq, V> Reorder for DequeMap<K, V> {
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        //SAFETY: upheld by caller
+        unsafe { Reorder::swap_unchecked(&mut self.deque, a, b) }
+    }
+
+    unsafe fn move_to_front_unchecked(&mut self, index: usize) {
+        //SAFETY: upheld by caller
+        unsafe { self.deque.move_to_front_unchecked(index) }
+    }
+}
+
+impl<K: Eq, V> PositionalLinearMap<K, V> for DequeMap<K, V> {}
+
+impl<K: Eq, V> Default for DequeMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V> TryFromIterator<(K, V)> for DequeMap<K, V> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, Self::Error> {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+impl<K: Eq + Clone, V: Clone> TryClone for DequeMap<K, V> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.deque.try_clone().map(|deque| DequeMap { deque })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.deque.try_clone_from(&source.deque)
+    }
+}
+
+impl<K: Eq, V> IntoIterator for DequeMap<K, V> {
+    type Item = (K, V);
+
+    type IntoIter = std::collections::vec_deque::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.deque.into_iter()
+    }
+}
+
+crate::std_traits::map_traits!([K: Eq, V] DequeMap<K, V>);
+crate::std_traits::map_index!([K: Eq, V] DequeMap<K, V>);
+
 #[cfg(feature = "serde")]
 impl<'a, K: Eq + serde::Serialize, V: PartialEq + serde::Serialize> serde::Serialize
     for DequeMap<K, V>
@@ -103,3 +240,15 @@ impl<'a, K: Eq + serde::Serialize, V: PartialEq + serde::Serialize> serde::Seria
         crate::serde::serialize_fallible_map(self, serializer)
     }
 }
+
+#[cfg(feature = "serde")]
+impl<'de, K: Eq + serde::Deserialize<'de>, V: serde::Deserialize<'de>> serde::Deserialize<'de>
+    for DequeMap<K, V>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_map(deserializer)
+    }
+}
@@ -1,2 +1,63 @@
+use std::collections::VecDeque;
+
+pub mod lru;
 pub mod map;
+pub mod self_organizing_map;
 pub mod set;
+
+///Removes and yields every element of a `VecDeque` for which `pred` returns true.
+///
+///Each visited element is popped from the front, and either yielded or pushed to the back. Pushing never reallocates
+///as an element was just popped, and once every element has been visited the kept elements are back in their original order.
+///Elements which have not been visited when the iterator is dropped are kept.
+pub(crate) struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool> {
+    deque: &'a mut VecDeque<T>,
+    pred: F,
+    ///Number of elements at the front of the deque which have not been visited.
+    remaining: usize,
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool> ExtractIf<'a, T, F> {
+    pub(crate) fn new(deque: &'a mut VecDeque<T>, pred: F) -> Self {
+        let remaining = deque.len();
+
+        Self {
+            deque,
+            pred,
+            remaining,
+        }
+    }
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'a, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.remaining > 0 {
+            //test the element in place, so that it is still in the deque if `pred` panics.
+            let extract = (self.pred)(self.deque.front_mut()?);
+
+            self.remaining -= 1;
+
+            let element = self.deque.pop_front()?;
+
+            match extract {
+                true => return Some(element),
+                false => self.deque.push_back(element),
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool> Drop for ExtractIf<'a, T, F> {
+    fn drop(&mut self) {
+        //the deque is [unvisited..., kept...]. Rotating puts the kept elements back in front.
+        self.deque.rotate_left(self.remaining);
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::TryReserveError;
 
-use crate::FallibleLinearSet;
+use crate::{FallibleLinearSet, TryClone, TryExtendError, TryFromIterator};
 
 use super::map::DequeMap;
 
@@ -46,6 +46,34 @@ impl<T: Eq> FallibleLinearSet<T> for DequeSet<T> {
     }
 }
 
+impl<T: Eq> Default for DequeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq> TryFromIterator<T> for DequeSet<T> {
+    type Error = TryExtendError<TryReserveError>;
+
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, Self::Error> {
+        let mut set = Self::new();
+        set.try_extend(iter)?;
+        Ok(set)
+    }
+}
+
+impl<T: Eq + Clone> TryClone for DequeSet<T> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        self.map.try_clone().map(|map| DequeSet { map })
+    }
+
+    fn try_clone_from(&mut self, source: &Self) -> Result<(), TryReserveError> {
+        self.map.try_clone_from(&source.map)
+    }
+}
+
+crate::std_traits::set_traits!([T: Eq] DequeSet<T>);
+
 #[cfg(feature = "serde")]
 impl<'a, T: Eq + serde::Serialize> serde::Serialize for DequeSet<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -55,3 +83,13 @@ impl<'a, T: Eq + serde::Serialize> serde::Serialize for DequeSet<T> {
         crate::serde::serialize_fallible_set(self, serializer)
     }
 }
+
+#[cfg(feature = "serde")]
+impl<'de, T: Eq + serde::Deserialize<'de>> serde::Deserialize<'de> for DequeSet<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::deserialize_fallible_set(deserializer)
+    }
+}
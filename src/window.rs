@@ -0,0 +1,77 @@
+use std::marker::PhantomData;
+
+use crate::FallibleLinearMap;
+
+///A view into a map with room reserved for `SLOTS` new keys ahead of time, created by `FallibleLinearMap::try_reserve_window`.
+///
+///All of the fallible work - allocating, or checking the remaining capacity of a `StackMap` - is done when the window is created,
+///so inserting through the window cannot fail. This is useful to hoist error handling out of hot loops.
+pub struct InfallibleMapWindow<
+    'backing,
+    K: Eq,
+    V,
+    Backing: FallibleLinearMap<K, V>,
+    const SLOTS: usize,
+> {
+    backing: &'backing mut Backing,
+    ///The number of reserved slots not yet consumed by inserting a new key.
+    remaining: usize,
+    phantom: PhantomData<(K, V)>,
+}
+
+impl<'backing, K: Eq, V, Backing: FallibleLinearMap<K, V>, const SLOTS: usize>
+    InfallibleMapWindow<'backing, K, V, Backing, SLOTS>
+{
+    ///The caller must have reserved space for at least `SLOTS` additional pairs in `backing`.
+    pub(crate) fn new(backing: &'backing mut Backing) -> Self {
+        Self {
+            backing,
+            remaining: SLOTS,
+            phantom: PhantomData,
+        }
+    }
+
+    ///Inserts a key-value pair into the map.
+    ///If the map did not have this key present, one of the reserved slots is consumed and None is returned.
+    ///If the map did have this key present, the value is updated, and the old value is returned. No slot is consumed.
+    ///
+    ///Panics if the key is not present and all `SLOTS` have already been consumed, as that insertion was never reserved.
+    ///Use `try_insert` if the number of new keys isn't known to be within `SLOTS`.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.try_insert(key, value) {
+            Ok(old) => old,
+            Err(_) => panic!("all {SLOTS} slots of this InfallibleMapWindow have been consumed"),
+        }
+    }
+
+    ///Like `insert`, but hands the pair back instead of panicking if the key is not present and no reserved slots remain.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
+        match self.backing.index_of(&key) {
+            Some(index) => {
+                //SAFETY: `index_of` only returns indices of pairs within the map.
+                let (_, v) = unsafe { self.backing.get_index_unchecked_mut(index) };
+                Ok(Some(std::mem::replace(v, value)))
+            }
+            None if self.remaining == 0 => Err((key, value)),
+            None => {
+                //we already know the key isn't present, and space for it was reserved when the window was created.
+                if self.backing.push_unchecked(key, value).is_err() {
+                    unreachable!("pushing into a reserved slot cannot fail")
+                }
+
+                self.remaining -= 1;
+                Ok(None)
+            }
+        }
+    }
+
+    ///The number of new keys which may still be inserted through this window.
+    pub fn remaining_slots(&self) -> usize {
+        self.remaining
+    }
+
+    ///Shared access to the map this window was created from.
+    pub fn map(&self) -> &Backing {
+        self.backing
+    }
+}